@@ -0,0 +1,339 @@
+use crate::entities::{AbilityType, Action, Philosopher};
+use crate::game_error::GameError;
+use crate::game_management::{available_packs, AvailablePacks, CardSet, OPENING_HAND_SIZE};
+use std::collections::HashSet;
+
+const BASE_SET: &str = "base set";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardProblem {
+    // the base set or the name of the card pack the card came from
+    pub source: String,
+    pub card: Option<String>,
+    pub message: String,
+}
+impl std::fmt::Display for CardProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.card {
+            Some(card) => write!(f, "[{}] '{}': {}", self.source, card, self.message),
+            None => write!(f, "[{}] {}", self.source, self.message),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationReport {
+    pub num_philosophers: usize,
+    pub num_actions: usize,
+    pub num_packs: usize,
+    pub problems: Vec<CardProblem>,
+}
+impl std::fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Checked {} philosophers and {} actions from the base set and {} card packs",
+            self.num_philosophers, self.num_actions, self.num_packs
+        )?;
+        for problem in &self.problems {
+            writeln!(f, "error: {}", problem)?;
+        }
+        if self.problems.is_empty() {
+            writeln!(f, "No problems found")?;
+        }
+        Ok(())
+    }
+}
+
+fn philosopher_problems(philosopher: &Philosopher) -> Vec<String> {
+    let mut problems = vec![];
+    if philosopher.starting_health == 0 {
+        problems.push("starting health is 0".into());
+    }
+    problems
+}
+
+fn action_problems(action: &Action) -> Vec<String> {
+    let mut problems = vec![];
+    match action.ability_type {
+        AbilityType::Damage { damage: 0, .. } => problems.push("ability deals 0 damage".into()),
+        AbilityType::Heal { heal: 0, .. } => problems.push("ability heals 0".into()),
+        _ => {}
+    }
+    for targeted_effect in action.additional_effects() {
+        let effect = &targeted_effect.effect;
+        if effect.duration() > 0 && effect.magnitude() == 0 {
+            problems.push(format!(
+                "additional effect lasts {} turns but has no magnitude",
+                effect.duration()
+            ));
+        }
+    }
+    problems
+}
+
+fn problem(source: &str, card: Option<&str>, message: String) -> CardProblem {
+    CardProblem {
+        source: source.into(),
+        card: card.map(String::from),
+        message,
+    }
+}
+
+// checks each card on its own and that no two cards share a name
+fn card_problems(source: &str, card_set: &CardSet) -> Vec<CardProblem> {
+    let problem = |card: Option<&str>, message: String| problem(source, card, message);
+    let mut problems = vec![];
+    for philosopher in &card_set.philosophers {
+        for message in philosopher_problems(philosopher) {
+            problems.push(problem(Some(&philosopher.name), message));
+        }
+    }
+    for action in &card_set.actions {
+        for message in action_problems(action) {
+            problems.push(problem(Some(action.name()), message));
+        }
+    }
+
+    let mut names = HashSet::new();
+    let all_names = card_set
+        .philosophers
+        .iter()
+        .map(|philosopher| philosopher.name.as_str())
+        .chain(card_set.actions.iter().map(|action| action.name()));
+    for name in all_names {
+        if !names.insert(name) {
+            problems.push(problem(
+                Some(name),
+                "name is used by more than one card".into(),
+            ));
+        }
+    }
+    problems
+}
+
+/// Checks every card in the set, then that the set can deal an opening hand.
+pub fn validate_card_set(source: &str, card_set: &CardSet) -> Vec<CardProblem> {
+    let problem = |card: Option<&str>, message: String| problem(source, card, message);
+    let mut problems = card_problems(source, card_set);
+
+    // one philosopher starts in hand and the opening hand is drawn from everything else
+    let deck_size = (card_set.philosophers.len() + card_set.actions.len()).saturating_sub(1);
+    if card_set.philosophers.is_empty() {
+        problems.push(problem(
+            None,
+            "no philosophers to start the game with".into(),
+        ));
+    } else if deck_size < usize::from(OPENING_HAND_SIZE) {
+        problems.push(problem(
+            None,
+            format!(
+                "deck has {} cards after the starting philosopher but the opening hand needs {}",
+                deck_size, OPENING_HAND_SIZE
+            ),
+        ));
+    }
+    problems
+}
+
+/// Validates the base set, then every available pack merged on top of it.
+pub fn validate_cards() -> ValidationReport {
+    validate(CardSet::base_entries(), available_packs())
+}
+
+// entries that fail to parse are reported and the rest of the base set is still checked
+fn validate(
+    (base_set, base_errors): (CardSet, Vec<GameError>),
+    packs: Result<AvailablePacks, GameError>,
+) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    for e in base_errors {
+        report.problems.push(problem(BASE_SET, None, e.to_string()));
+    }
+    report.num_philosophers = base_set.philosophers.len();
+    report.num_actions = base_set.actions.len();
+    report
+        .problems
+        .extend(validate_card_set(BASE_SET, &base_set));
+
+    match packs {
+        Ok(available) => {
+            report.num_packs = available.packs.len() + available.broken.len();
+            report.problems.extend(pack_problems(&base_set, available));
+        }
+        Err(e) => report
+            .problems
+            .push(problem("card packs", None, e.to_string())),
+    }
+    report
+}
+
+// adding cards can't shrink the deck, so a pack only needs its own cards checked
+fn pack_problems(base_set: &CardSet, available: AvailablePacks) -> Vec<CardProblem> {
+    let mut problems = vec![];
+    for (folder, e) in available.broken {
+        problems.push(problem(&folder, None, e.to_string()));
+    }
+    for pack in available.packs {
+        let source = pack.manifest.name.clone();
+        let pack_cards = CardSet {
+            philosophers: pack.philosophers.clone(),
+            actions: pack.actions.clone(),
+        };
+        problems.extend(card_problems(&source, &pack_cards));
+        if let Err(e) = base_set.clone().add_pack(pack) {
+            problems.push(problem(&source, None, e.to_string()));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{CoreSchool, Effect, EffectTarget, TargetedEffect};
+    use crate::test_utils::get_temp_dir;
+
+    fn get_example_card_set() -> CardSet {
+        CardSet {
+            philosophers: vec![
+                Philosopher::new("Plato".into(), CoreSchool::Rationalist, 10),
+                Philosopher::new("Hume".into(), CoreSchool::Empiricist, 10),
+            ],
+            actions: (1..=3)
+                .map(|damage| {
+                    Action::new(
+                        format!("Argument {}", damage),
+                        "desc".into(),
+                        CoreSchool::Skeptic,
+                        AbilityType::Damage {
+                            damage,
+                            duration: 0,
+                        },
+                        None,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn messages(problems: &[CardProblem]) -> Vec<&str> {
+        problems
+            .iter()
+            .map(|problem| problem.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_base_set_is_valid() {
+        assert_eq!(
+            validate_card_set(BASE_SET, &CardSet::base().unwrap()),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_example_card_set_is_valid() {
+        assert_eq!(validate_card_set("test", &get_example_card_set()), vec![]);
+    }
+
+    #[test]
+    fn test_catches_card_problems() {
+        let mut card_set = get_example_card_set();
+        card_set.philosophers[0].starting_health = 0;
+        card_set.philosophers[1].name = "Argument 1".into();
+        card_set.actions.push(Action::new(
+            "Whisper".into(),
+            "desc".into(),
+            CoreSchool::Skeptic,
+            AbilityType::Heal {
+                heal: 0,
+                duration: 2,
+            },
+            Some(vec![TargetedEffect {
+                effect: Effect::Poison {
+                    damage: 0,
+                    duration: 2,
+                },
+                target: EffectTarget::Opponent,
+            }]),
+        ));
+        let problems = validate_card_set("test", &card_set);
+        assert_eq!(
+            messages(&problems),
+            [
+                "starting health is 0",
+                "ability heals 0",
+                "additional effect lasts 2 turns but has no magnitude",
+                "name is used by more than one card",
+            ]
+        );
+        assert_eq!(problems[3].card.as_deref(), Some("Argument 1"));
+    }
+
+    #[test]
+    fn test_catches_deck_too_small_for_opening_hand() {
+        let mut card_set = get_example_card_set();
+        card_set.actions.truncate(2);
+        assert_eq!(
+            messages(&validate_card_set("test", &card_set)),
+            ["deck has 3 cards after the starting philosopher but the opening hand needs 4"]
+        );
+        card_set.philosophers.clear();
+        assert_eq!(
+            messages(&validate_card_set("test", &card_set)),
+            ["no philosophers to start the game with"]
+        );
+    }
+
+    #[test]
+    fn test_reports_each_broken_pack() {
+        let available = AvailablePacks {
+            packs: vec![],
+            broken: vec![
+                (
+                    "stoics".into(),
+                    GameError::AssetLoad {
+                        path: "packs/stoics/philosophers.yaml".into(),
+                        reason: "unknown variant `Stoic`".into(),
+                    },
+                ),
+                ("cynics".into(), GameError::UnknownPack("cynics".into())),
+            ],
+        };
+        let problems = pack_problems(&get_example_card_set(), available);
+        assert_eq!(
+            problems
+                .iter()
+                .map(|problem| problem.source.as_str())
+                .collect::<Vec<_>>(),
+            ["stoics", "cynics"]
+        );
+    }
+
+    #[test]
+    fn test_reports_every_bad_base_entry() {
+        let dir = get_temp_dir("assets");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("philosophers.yaml"),
+            "- name: Plato\n  school: Rationalist\n  starting_health: 10\n\
+             - name: Zeno\n  school: Stoic\n  starting_health: 9\n\
+             - name: Hume\n  school: Empiricist\n",
+        )
+        .unwrap();
+        let base_entries = CardSet::base_entries_from(Some(&dir));
+        std::fs::remove_dir_all(&dir).unwrap();
+        let report = validate(base_entries, Ok(AvailablePacks::default()));
+        assert_eq!(report.num_philosophers, 1);
+        assert_eq!(report.problems.len(), 2);
+        assert!(report.problems[0].message.contains("entry 2 ('Zeno')"));
+        assert!(report.problems[0]
+            .message
+            .contains("unknown variant `Stoic`"));
+        assert!(report.problems[1].message.contains("entry 3 ('Hume')"));
+        assert!(report.problems[1]
+            .message
+            .contains("missing field `starting_health`"));
+    }
+}
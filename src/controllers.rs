@@ -0,0 +1,110 @@
+pub mod greedy_ai;
+pub mod mcts_ai;
+pub mod random_ai;
+
+pub use greedy_ai::GreedyAi;
+pub use mcts_ai::{Difficulty, MctsAi};
+pub use random_ai::RandomAi;
+
+use crate::entities::CardId;
+use crate::game_management::GameBoard;
+use std::str::FromStr;
+
+pub trait Player {
+    fn name(&self) -> &str;
+    fn choose_turn(&mut self, game_board: &GameBoard) -> Vec<CardId>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ControllerKind {
+    Human,
+    RandomAi,
+    GreedyAi,
+    MctsAi(Difficulty),
+}
+impl ControllerKind {
+    pub fn build(&self, seed: Option<u64>) -> Option<Box<dyn Player>> {
+        match self {
+            ControllerKind::Human => None,
+            ControllerKind::RandomAi => Some(Box::new(RandomAi::new(seed))),
+            ControllerKind::GreedyAi => Some(Box::new(GreedyAi::default())),
+            ControllerKind::MctsAi(difficulty) => {
+                Some(Box::new(MctsAi::with_difficulty(*difficulty, seed)))
+            }
+        }
+    }
+}
+impl std::fmt::Display for ControllerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ControllerKind::Human => write!(f, "human"),
+            ControllerKind::RandomAi => write!(f, "random"),
+            ControllerKind::GreedyAi => write!(f, "greedy"),
+            ControllerKind::MctsAi(Difficulty::Easy) => write!(f, "mcts-easy"),
+            ControllerKind::MctsAi(Difficulty::Medium) => write!(f, "mcts-medium"),
+            ControllerKind::MctsAi(Difficulty::Hard) => write!(f, "mcts-hard"),
+        }
+    }
+}
+impl FromStr for ControllerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "human" => Ok(ControllerKind::Human),
+            "random" => Ok(ControllerKind::RandomAi),
+            "greedy" => Ok(ControllerKind::GreedyAi),
+            "mcts-easy" => Ok(ControllerKind::MctsAi(Difficulty::Easy)),
+            "mcts" | "mcts-medium" => Ok(ControllerKind::MctsAi(Difficulty::Medium)),
+            "mcts-hard" => Ok(ControllerKind::MctsAi(Difficulty::Hard)),
+            _ => Err(format!(
+                "unknown controller '{}', expected human, random, greedy, mcts-easy, mcts-medium or mcts-hard",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controller_kind_from_str() {
+        assert_eq!("human".parse(), Ok(ControllerKind::Human));
+        assert_eq!("Random".parse(), Ok(ControllerKind::RandomAi));
+        assert_eq!("GREEDY".parse(), Ok(ControllerKind::GreedyAi));
+        assert_eq!(
+            "mcts".parse(),
+            Ok(ControllerKind::MctsAi(Difficulty::Medium))
+        );
+        assert_eq!(
+            "mcts-hard".parse(),
+            Ok(ControllerKind::MctsAi(Difficulty::Hard))
+        );
+        assert!("socrates".parse::<ControllerKind>().is_err());
+    }
+
+    #[test]
+    fn test_controller_kind_display_round_trips() {
+        for kind in [
+            ControllerKind::Human,
+            ControllerKind::RandomAi,
+            ControllerKind::GreedyAi,
+            ControllerKind::MctsAi(Difficulty::Easy),
+            ControllerKind::MctsAi(Difficulty::Medium),
+            ControllerKind::MctsAi(Difficulty::Hard),
+        ] {
+            assert_eq!(kind.to_string().parse(), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn test_build_controller() {
+        assert!(ControllerKind::Human.build(None).is_none());
+        assert_eq!(
+            ControllerKind::GreedyAi.build(None).unwrap().name(),
+            "Greedy AI"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::controllers::Player;
+use crate::entities::CardId;
+use crate::game_management::{GameBoard, GameOverReason, Seat};
+
+#[derive(Default)]
+pub struct GreedyAi {}
+impl GreedyAi {
+    fn score(game_board: &GameBoard, seat: &Seat) -> i32 {
+        match game_board.game_over_reason() {
+            Some(GameOverReason::PhilosopherDefeated { loser })
+            | Some(GameOverReason::OutOfCards { loser, .. }) => {
+                if loser == seat {
+                    i32::MIN
+                } else {
+                    i32::MAX
+                }
+            }
+            None => {
+                i32::from(game_board.philosopher_health(seat))
+                    - i32::from(game_board.philosopher_health(&seat.other()))
+            }
+        }
+    }
+}
+impl Player for GreedyAi {
+    fn name(&self) -> &str {
+        "Greedy AI"
+    }
+
+    fn choose_turn(&mut self, game_board: &GameBoard) -> Vec<CardId> {
+        let Some(seat) = game_board.game_phase().active_seat() else {
+            return vec![];
+        };
+        let mut best_turn = vec![];
+        let mut best_score = i32::MIN;
+        for turn in game_board.legal_turns() {
+            let mut next_board = game_board.clone();
+            if next_board.process_turn(turn.clone()).is_err() {
+                continue;
+            }
+            let score = GreedyAi::score(&next_board, &seat);
+            // prefer playing cards over passing when the scores are tied
+            if score > best_score || (score == best_score && best_turn.is_empty()) {
+                best_score = score;
+                best_turn = turn;
+            }
+        }
+        best_turn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_example_board;
+
+    #[test]
+    fn test_greedy_ai_damages_opponent() {
+        let game_board = get_example_board();
+        let mut greedy_ai = GreedyAi::default();
+        let turn = greedy_ai.choose_turn(&game_board);
+        assert_eq!(game_board.validate_turn(&turn), Ok(()));
+        assert!(turn.contains(&CardId(0)));
+    }
+
+    #[test]
+    fn test_greedy_ai_heals_when_damaged() {
+        let mut game_board = get_example_board();
+        game_board
+            .player_1_hand
+            .active_philosopher
+            .as_mut()
+            .unwrap()
+            .apply_direct_damage(5);
+        let mut greedy_ai = GreedyAi::default();
+        let turn = greedy_ai.choose_turn(&game_board);
+        assert!(turn.contains(&CardId(0)));
+        assert!(turn.contains(&CardId(1)));
+    }
+
+    #[test]
+    fn test_greedy_ai_takes_winning_turn() {
+        let mut game_board = get_example_board();
+        game_board
+            .player_2_hand
+            .active_philosopher
+            .as_mut()
+            .unwrap()
+            .apply_direct_damage(11);
+        let mut greedy_ai = GreedyAi::default();
+        let turn = greedy_ai.choose_turn(&game_board);
+        let mut next_board = game_board.clone();
+        next_board.process_turn(turn).unwrap();
+        assert_eq!(
+            next_board.game_over_reason(),
+            Some(&GameOverReason::PhilosopherDefeated {
+                loser: Seat::Player2
+            })
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::controllers::Player;
+use crate::entities::CardId;
+use crate::game_management::{GameBoard, GameOverReason, Seat};
+use rand::{rng, rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+const EXPLORATION: f64 = 0.7;
+const MAX_PLAYOUT_TURNS: u32 = 40;
+const RANDOM_TURN_ATTEMPTS: u32 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SearchBudget {
+    Iterations(u32),
+    Time(Duration),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    /// Searches for a fixed two seconds per turn. The search runs on the caller's thread,
+    /// so the game screen stops taking input for that long.
+    Hard,
+}
+impl Difficulty {
+    pub fn budget(&self) -> SearchBudget {
+        match self {
+            Difficulty::Easy => SearchBudget::Iterations(50),
+            Difficulty::Medium => SearchBudget::Iterations(300),
+            Difficulty::Hard => SearchBudget::Time(Duration::from_secs(2)),
+        }
+    }
+}
+
+struct Node {
+    turn: Vec<CardId>,
+    // the seat that played `turn` to reach this node, none for the root
+    mover: Option<Seat>,
+    children: Vec<usize>,
+    visits: u32,
+    availability: u32,
+    total_reward: f64,
+}
+impl Node {
+    fn new(turn: Vec<CardId>, mover: Option<Seat>) -> Self {
+        Node {
+            turn,
+            mover,
+            children: vec![],
+            visits: 0,
+            availability: 1,
+            total_reward: 0.0,
+        }
+    }
+
+    fn upper_confidence_bound(&self) -> f64 {
+        let visits = f64::from(self.visits);
+        self.total_reward / visits
+            + EXPLORATION * (f64::from(self.availability).ln() / visits).sqrt()
+    }
+}
+
+/// Information set Monte Carlo tree search: every iteration searches a different guess at the
+/// cards the active player can't see, and only follows turns that are legal in that guess.
+pub struct MctsAi {
+    budget: SearchBudget,
+    rng: StdRng,
+}
+impl MctsAi {
+    pub fn new(budget: SearchBudget, seed: Option<u64>) -> Self {
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rng()),
+        };
+        MctsAi { budget, rng }
+    }
+
+    pub fn with_difficulty(difficulty: Difficulty, seed: Option<u64>) -> Self {
+        MctsAi::new(difficulty.budget(), seed)
+    }
+
+    fn run_iteration(&mut self, nodes: &mut Vec<Node>, root_board: &GameBoard, seat: &Seat) {
+        let mut board = root_board.determinize(seat, &mut self.rng);
+        let mut path = vec![0];
+        let mut node = 0;
+        while board.game_over_reason().is_none() {
+            let Some(mover) = board.game_phase().active_seat() else {
+                break;
+            };
+            let legal_turns = board.legal_turns();
+            let untried_turns: Vec<&Vec<CardId>> = legal_turns
+                .iter()
+                .filter(|turn| {
+                    !nodes[node]
+                        .children
+                        .iter()
+                        .any(|c| &nodes[*c].turn == *turn)
+                })
+                .collect();
+            if let Some(turn) = untried_turns.choose(&mut self.rng) {
+                let turn = turn.to_vec();
+                if board.process_turn(turn.clone()).is_err() {
+                    break;
+                }
+                nodes.push(Node::new(turn, Some(mover)));
+                let child = nodes.len() - 1;
+                nodes[node].children.push(child);
+                path.push(child);
+                break;
+            }
+            let available_children: Vec<usize> = nodes[node]
+                .children
+                .iter()
+                .copied()
+                .filter(|c| legal_turns.contains(&nodes[*c].turn))
+                .collect();
+            for child in &available_children {
+                nodes[*child].availability += 1;
+            }
+            let Some(child) = available_children.into_iter().max_by(|a, b| {
+                nodes[*a]
+                    .upper_confidence_bound()
+                    .total_cmp(&nodes[*b].upper_confidence_bound())
+            }) else {
+                break;
+            };
+            if board.process_turn(nodes[child].turn.clone()).is_err() {
+                break;
+            }
+            path.push(child);
+            node = child;
+        }
+
+        let reward = self.playout(board, seat);
+        for node in path {
+            nodes[node].visits += 1;
+            nodes[node].total_reward += match nodes[node].mover {
+                Some(mover) if &mover != seat => 1.0 - reward,
+                _ => reward,
+            };
+        }
+    }
+
+    // plays random turns to the end of the game and scores the result for `seat` between 0 and 1
+    fn playout(&mut self, mut board: GameBoard, seat: &Seat) -> f64 {
+        for _ in 0..MAX_PLAYOUT_TURNS {
+            if board.game_over_reason().is_some() {
+                break;
+            }
+            let turn = self.random_turn(&board);
+            if board.process_turn(turn).is_err() {
+                break;
+            }
+        }
+        match board.game_over_reason() {
+            Some(GameOverReason::PhilosopherDefeated { loser })
+            | Some(GameOverReason::OutOfCards { loser, .. }) => {
+                if loser == seat {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            None => {
+                let own_health = f64::from(board.philosopher_health(seat));
+                let opponent_health = f64::from(board.philosopher_health(&seat.other()));
+                let total_health = (own_health + opponent_health).max(1.0);
+                0.5 + 0.5 * (own_health - opponent_health) / total_health
+            }
+        }
+    }
+
+    // much cheaper than picking from `legal_turns`, which matters when it runs every playout step
+    fn random_turn(&mut self, board: &GameBoard) -> Vec<CardId> {
+        let Some(seat) = board.game_phase().active_seat() else {
+            return vec![];
+        };
+        let hand_ids: Vec<CardId> = board
+            .hand(&seat)
+            .inactive_cards
+            .iter()
+            .map(|card| card.id)
+            .collect();
+        let max_cards =
+            usize::from(board.game_config.max_cards_played_per_turn()).min(hand_ids.len());
+        if max_cards == 0 {
+            return vec![];
+        }
+        for _ in 0..RANDOM_TURN_ATTEMPTS {
+            let num_cards = self.rng.random_range(1..=max_cards);
+            let turn: Vec<CardId> = hand_ids
+                .choose_multiple(&mut self.rng, num_cards)
+                .copied()
+                .collect();
+            if board.validate_turn(&turn).is_ok() {
+                return turn;
+            }
+        }
+        vec![]
+    }
+}
+impl Player for MctsAi {
+    fn name(&self) -> &str {
+        "MCTS AI"
+    }
+
+    fn choose_turn(&mut self, game_board: &GameBoard) -> Vec<CardId> {
+        let Some(seat) = game_board.game_phase().active_seat() else {
+            return vec![];
+        };
+        let mut nodes = vec![Node::new(vec![], None)];
+        let start = Instant::now();
+        let mut iterations = 0;
+        while match self.budget {
+            SearchBudget::Iterations(max_iterations) => iterations < max_iterations,
+            SearchBudget::Time(max_time) => start.elapsed() < max_time,
+        } {
+            self.run_iteration(&mut nodes, game_board, &seat);
+            iterations += 1;
+        }
+        nodes[0]
+            .children
+            .iter()
+            .max_by_key(|c| nodes[**c].visits)
+            .map(|c| nodes[*c].turn.clone())
+            .unwrap_or_else(|| {
+                game_board
+                    .legal_turns()
+                    .into_iter()
+                    .next()
+                    .unwrap_or_default()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_example_board;
+
+    #[test]
+    fn test_mcts_ai_chooses_legal_turn() {
+        let game_board = get_example_board();
+        let mut mcts_ai = MctsAi::new(SearchBudget::Iterations(30), Some(42));
+        let turn = mcts_ai.choose_turn(&game_board);
+        assert_eq!(game_board.validate_turn(&turn), Ok(()));
+    }
+
+    #[test]
+    fn test_mcts_ai_with_seed_is_repeatable() {
+        let game_board = get_example_board();
+        let mut mcts_ai_1 = MctsAi::new(SearchBudget::Iterations(30), Some(7));
+        let mut mcts_ai_2 = MctsAi::new(SearchBudget::Iterations(30), Some(7));
+        assert_eq!(
+            mcts_ai_1.choose_turn(&game_board),
+            mcts_ai_2.choose_turn(&game_board)
+        );
+    }
+
+    #[test]
+    fn test_mcts_ai_takes_winning_turn() {
+        let mut game_board = get_example_board();
+        game_board
+            .player_2_hand
+            .active_philosopher
+            .as_mut()
+            .unwrap()
+            .apply_direct_damage(11);
+        let mut mcts_ai = MctsAi::new(SearchBudget::Iterations(100), Some(42));
+        let turn = mcts_ai.choose_turn(&game_board);
+        let mut next_board = game_board.clone();
+        next_board.process_turn(turn).unwrap();
+        assert_eq!(
+            next_board.game_over_reason(),
+            Some(&GameOverReason::PhilosopherDefeated {
+                loser: Seat::Player2
+            })
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::controllers::Player;
+use crate::entities::CardId;
+use crate::game_management::GameBoard;
+use rand::{rng, rngs::StdRng, seq::IndexedRandom, SeedableRng};
+
+pub struct RandomAi {
+    rng: StdRng,
+}
+impl RandomAi {
+    pub fn new(seed: Option<u64>) -> Self {
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rng()),
+        };
+        RandomAi { rng }
+    }
+}
+impl Player for RandomAi {
+    fn name(&self) -> &str {
+        "Random AI"
+    }
+
+    fn choose_turn(&mut self, game_board: &GameBoard) -> Vec<CardId> {
+        let legal_turns = game_board.legal_turns();
+        // only pass when there's nothing else to do, otherwise games drag on forever
+        let playing_turns: Vec<&Vec<CardId>> =
+            legal_turns.iter().filter(|turn| !turn.is_empty()).collect();
+        playing_turns
+            .choose(&mut self.rng)
+            .map(|turn| turn.to_vec())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_example_board;
+
+    #[test]
+    fn test_random_ai_chooses_legal_turn() {
+        let game_board = get_example_board();
+        let mut random_ai = RandomAi::new(Some(42));
+        let turn = random_ai.choose_turn(&game_board);
+        assert!(!turn.is_empty());
+        assert_eq!(game_board.validate_turn(&turn), Ok(()));
+    }
+
+    #[test]
+    fn test_random_ai_with_seed_is_repeatable() {
+        let game_board = get_example_board();
+        let mut random_ai_1 = RandomAi::new(Some(7));
+        let mut random_ai_2 = RandomAi::new(Some(7));
+        for _ in 0..5 {
+            assert_eq!(
+                random_ai_1.choose_turn(&game_board),
+                random_ai_2.choose_turn(&game_board)
+            );
+        }
+    }
+}
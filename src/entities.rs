@@ -1,7 +1,11 @@
 pub mod base_cards;
+pub mod card_instance;
 pub mod effect;
 pub mod in_play_philosopher;
+pub mod school_matchups;
 
 pub use base_cards::{AbilityType, Action, Card, CoreSchool, Philosopher};
-pub use effect::Effect;
+pub use card_instance::{CardId, CardIdGenerator, CardInstance};
+pub use effect::{ActiveEffect, Effect, EffectTarget, TargetedEffect};
 pub use in_play_philosopher::{DamageCounter, InPlayPhilosopher};
+pub use school_matchups::{MatchupOutcome, SchoolMatchups};
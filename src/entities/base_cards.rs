@@ -1,9 +1,9 @@
-use crate::entities::Effect;
 use crate::entities::InPlayPhilosopher;
+use crate::entities::TargetedEffect;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum CoreSchool {
     Rationalist,
     Empiricist,
@@ -17,20 +17,38 @@ pub enum AbilityType {
     Heal { heal: u8, duration: u8 },
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Card {
     Action(Action),
     Philosopher(Philosopher),
     InPlayPhilosopher(InPlayPhilosopher),
 }
 
+impl Card {
+    pub fn name(&self) -> &str {
+        match self {
+            Card::Action(action) => action.name(),
+            Card::Philosopher(philosopher) => &philosopher.name,
+            Card::InPlayPhilosopher(in_play_philosopher) => &in_play_philosopher.philosopher.name,
+        }
+    }
+
+    pub fn school(&self) -> &CoreSchool {
+        match self {
+            Card::Action(action) => action.school(),
+            Card::Philosopher(philosopher) => &philosopher.school,
+            Card::InPlayPhilosopher(in_play_philosopher) => &in_play_philosopher.philosopher.school,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Action {
     name: String,
     description: String,
     school: CoreSchool,
     pub ability_type: AbilityType,
-    additional_effects: Option<Vec<Effect>>,
+    additional_effects: Option<Vec<TargetedEffect>>,
 }
 impl Action {
     pub fn new(
@@ -38,7 +56,7 @@ impl Action {
         description: String,
         school: CoreSchool,
         ability_type: AbilityType,
-        additional_effects: Option<Vec<Effect>>,
+        additional_effects: Option<Vec<TargetedEffect>>,
     ) -> Self {
         Self {
             name,
@@ -48,6 +66,22 @@ impl Action {
             additional_effects,
         }
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn school(&self) -> &CoreSchool {
+        &self.school
+    }
+
+    pub fn additional_effects(&self) -> &[TargetedEffect] {
+        self.additional_effects.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -0,0 +1,46 @@
+use crate::entities::Card;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct CardId(pub u32);
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CardInstance {
+    pub id: CardId,
+    pub card: Card,
+}
+
+#[derive(Debug, Default)]
+pub struct CardIdGenerator {
+    next_id: u32,
+}
+impl CardIdGenerator {
+    pub fn next_id(&mut self) -> CardId {
+        let id = CardId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    pub fn instantiate(&mut self, card: Card) -> CardInstance {
+        CardInstance {
+            id: self.next_id(),
+            card,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_example_damage_action;
+
+    #[test]
+    fn test_ids_are_sequential_and_unique() {
+        let mut generator = CardIdGenerator::default();
+        let first = generator.instantiate(get_example_damage_action(1, 0));
+        let second = generator.instantiate(get_example_damage_action(1, 0));
+        assert_eq!(first.id, CardId(0));
+        assert_eq!(second.id, CardId(1));
+        assert_eq!(generator.next_id(), CardId(2));
+    }
+}
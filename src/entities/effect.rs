@@ -1,7 +1,13 @@
 use crate::entities::DamageCounter;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum EffectTarget {
+    Own,
+    Opponent,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Effect {
     Poison { damage: u8, duration: u8 },
     Recovery { heal: u8, duration: u8 },
@@ -39,6 +45,20 @@ impl Effect {
     }
 }
 
+/// An effect on an in-play philosopher along with the name of the card that caused it.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct ActiveEffect {
+    pub effect: Effect,
+    pub source: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TargetedEffect {
+    #[serde(flatten)]
+    pub effect: Effect,
+    pub target: EffectTarget,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +150,28 @@ mod tests {
         assert_eq!(heal_effect.duration(), 0);
         assert!(heal_effect.is_expired());
     }
+
+    #[test]
+    fn test_targeted_effect_from_yaml() {
+        let yaml = "
+- Poison:
+    damage: 2
+    duration: 3
+  target: Opponent
+- Recovery:
+    heal: 1
+    duration: 2
+  target: Own
+";
+        let effects: Vec<TargetedEffect> = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(effects.len(), 2);
+        assert_eq!(effects[0].target, EffectTarget::Opponent);
+        assert_eq!(effects[0].effect.magnitude(), 2);
+        assert_eq!(effects[0].effect.duration(), 3);
+        assert_eq!(effects[1].target, EffectTarget::Own);
+        assert!(matches!(
+            effects[1].effect,
+            Effect::Recovery { heal: 1, .. }
+        ));
+    }
 }
@@ -1,6 +1,7 @@
-use crate::entities::{Effect, Philosopher};
+use crate::entities::{ActiveEffect, Effect, Philosopher};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DamageCounter {
     pub damage_counter: u8,
 }
@@ -14,18 +15,18 @@ impl DamageCounter {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 enum DeathStatus {
     Dead,
     Alive,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InPlayPhilosopher {
     pub philosopher: Philosopher,
     pub damage_counter: DamageCounter,
-    pub effects: Vec<Effect>,
-    pub death_status: DeathStatus,
+    pub effects: Vec<ActiveEffect>,
+    death_status: DeathStatus,
 }
 impl InPlayPhilosopher {
     pub fn new(philosopher: Philosopher) -> Self {
@@ -53,22 +54,36 @@ impl InPlayPhilosopher {
         }
     }
 
-    pub fn add_effect(&mut self, effect: Effect) {
-        self.effects.push(effect);
+    pub fn add_effect(&mut self, effect: Effect, source: &str) {
+        self.effects.push(ActiveEffect {
+            effect,
+            source: source.into(),
+        });
     }
 
-    pub fn apply_existing_effects(&mut self) {
-        for effect in &mut self.effects {
-            effect.apply(&mut self.damage_counter);
+    // returns each effect that ticked, as it is after ticking, with the health it actually changed
+    pub fn apply_existing_effects(&mut self) -> Vec<(ActiveEffect, u8)> {
+        let mut ticked_effects = vec![];
+        for active_effect in &mut self.effects {
+            let health_before = self
+                .philosopher
+                .starting_health
+                .saturating_sub(self.damage_counter.damage_counter);
+            active_effect.effect.apply(&mut self.damage_counter);
+            let health_after = self
+                .philosopher
+                .starting_health
+                .saturating_sub(self.damage_counter.damage_counter);
+            ticked_effects.push((active_effect.clone(), health_before.abs_diff(health_after)));
             if self.death_status == DeathStatus::Dead {
                 break;
             }
         }
         self.update_death();
         // Remove effects where duration == 0
-        self.effects.retain(|effect| match effect {
-            Effect::Poison { duration, .. } | Effect::Recovery { duration, .. } => *duration > 0,
-        });
+        self.effects
+            .retain(|active_effect| !active_effect.effect.is_expired());
+        ticked_effects
     }
 
     pub fn apply_direct_heal(&mut self, heal: u8) {
@@ -119,14 +134,20 @@ mod tests {
     fn test_effect_application() {
         let starting_health = 10;
         let mut philos = get_example_in_play_philosopher("test".into(), starting_health);
-        philos.add_effect(Effect::Poison {
-            damage: 3,
-            duration: 2,
-        });
-        philos.add_effect(Effect::Recovery {
-            heal: 1,
-            duration: 1,
-        });
+        philos.add_effect(
+            Effect::Poison {
+                damage: 3,
+                duration: 2,
+            },
+            "test_source",
+        );
+        philos.add_effect(
+            Effect::Recovery {
+                heal: 1,
+                duration: 1,
+            },
+            "test_source",
+        );
         assert_eq!(philos.effects.len(), 2);
         philos.apply_existing_effects();
         assert_eq!(philos.remaining_health(), 8);
@@ -139,14 +160,20 @@ mod tests {
     fn test_effect_application_after_death() {
         let starting_health = 3;
         let mut philos = get_example_in_play_philosopher("test".into(), starting_health);
-        philos.add_effect(Effect::Poison {
-            damage: 10,
-            duration: 2,
-        });
-        philos.add_effect(Effect::Recovery {
-            heal: 1,
-            duration: 1,
-        });
+        philos.add_effect(
+            Effect::Poison {
+                damage: 10,
+                duration: 2,
+            },
+            "test_source",
+        );
+        philos.add_effect(
+            Effect::Recovery {
+                heal: 1,
+                duration: 1,
+            },
+            "test_source",
+        );
         assert_eq!(philos.effects.len(), 2);
         philos.apply_existing_effects();
         assert_eq!(philos.remaining_health(), 0);
@@ -154,6 +181,27 @@ mod tests {
         assert_eq!(philos.death_status, DeathStatus::Dead);
     }
 
+    #[test]
+    fn test_effect_application_reports_ticks() {
+        let mut philos = get_example_in_play_philosopher("test".into(), 10);
+        philos.apply_direct_damage(1);
+        philos.add_effect(
+            Effect::Recovery {
+                heal: 3,
+                duration: 1,
+            },
+            "test_heal",
+        );
+        let ticked_effects = philos.apply_existing_effects();
+        assert_eq!(ticked_effects.len(), 1);
+        let (active_effect, amount) = &ticked_effects[0];
+        assert_eq!(active_effect.source, "test_heal");
+        assert_eq!(active_effect.effect.duration(), 0);
+        // only the missing health is restored
+        assert_eq!(*amount, 1);
+        assert!(philos.effects.is_empty());
+    }
+
     #[test]
     fn test_death() {
         let starting_health = 2;
@@ -0,0 +1,139 @@
+use crate::entities::CoreSchool;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Matchup {
+    pub school: CoreSchool,
+    pub beats: CoreSchool,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum MatchupOutcome {
+    Advantage,
+    Disadvantage,
+    Neutral,
+}
+
+/// Scales ability magnitudes by comparing the school of a card with the school of its target.
+/// Percentages are applied to the base magnitude and rounded to the nearest whole point.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SchoolMatchups {
+    pub advantage_percent: u16,
+    pub disadvantage_percent: u16,
+    pub matchups: Vec<Matchup>,
+}
+impl Default for SchoolMatchups {
+    fn default() -> Self {
+        Self {
+            advantage_percent: 100,
+            disadvantage_percent: 100,
+            matchups: vec![],
+        }
+    }
+}
+impl SchoolMatchups {
+    pub fn outcome(&self, card_school: &CoreSchool, target_school: &CoreSchool) -> MatchupOutcome {
+        let beats = |winner: &CoreSchool, loser: &CoreSchool| {
+            self.matchups
+                .iter()
+                .any(|m| &m.school == winner && &m.beats == loser)
+        };
+        if beats(card_school, target_school) {
+            MatchupOutcome::Advantage
+        } else if beats(target_school, card_school) {
+            MatchupOutcome::Disadvantage
+        } else {
+            MatchupOutcome::Neutral
+        }
+    }
+
+    pub fn scale(&self, amount: u8, outcome: MatchupOutcome) -> u8 {
+        let percent = match outcome {
+            MatchupOutcome::Advantage => self.advantage_percent,
+            MatchupOutcome::Disadvantage => self.disadvantage_percent,
+            MatchupOutcome::Neutral => return amount,
+        };
+        let scaled = (u32::from(amount) * u32::from(percent) + 50) / 100;
+        scaled.min(u8::MAX.into()) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_example_matchups() -> SchoolMatchups {
+        SchoolMatchups {
+            advantage_percent: 150,
+            disadvantage_percent: 50,
+            matchups: vec![
+                Matchup {
+                    school: CoreSchool::Rationalist,
+                    beats: CoreSchool::Skeptic,
+                },
+                Matchup {
+                    school: CoreSchool::Skeptic,
+                    beats: CoreSchool::Empiricist,
+                },
+                Matchup {
+                    school: CoreSchool::Empiricist,
+                    beats: CoreSchool::Rationalist,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_outcome() {
+        let matchups = get_example_matchups();
+        assert_eq!(
+            matchups.outcome(&CoreSchool::Rationalist, &CoreSchool::Skeptic),
+            MatchupOutcome::Advantage
+        );
+        assert_eq!(
+            matchups.outcome(&CoreSchool::Rationalist, &CoreSchool::Empiricist),
+            MatchupOutcome::Disadvantage
+        );
+        assert_eq!(
+            matchups.outcome(&CoreSchool::Skeptic, &CoreSchool::Skeptic),
+            MatchupOutcome::Neutral
+        );
+    }
+
+    #[test]
+    fn test_default_is_neutral() {
+        let matchups = SchoolMatchups::default();
+        assert_eq!(
+            matchups.outcome(&CoreSchool::Rationalist, &CoreSchool::Skeptic),
+            MatchupOutcome::Neutral
+        );
+        assert_eq!(matchups.scale(4, MatchupOutcome::Advantage), 4);
+    }
+
+    #[test]
+    fn test_scale() {
+        let matchups = get_example_matchups();
+        assert_eq!(matchups.scale(2, MatchupOutcome::Advantage), 3);
+        assert_eq!(matchups.scale(5, MatchupOutcome::Disadvantage), 3);
+        assert_eq!(matchups.scale(5, MatchupOutcome::Neutral), 5);
+        assert_eq!(matchups.scale(200, MatchupOutcome::Advantage), u8::MAX);
+    }
+
+    #[test]
+    fn test_load_from_yaml() {
+        let yaml = "
+advantage_percent: 200
+disadvantage_percent: 0
+matchups:
+  - school: Empiricist
+    beats: Skeptic
+";
+        let matchups: SchoolMatchups = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            matchups.outcome(&CoreSchool::Skeptic, &CoreSchool::Empiricist),
+            MatchupOutcome::Disadvantage
+        );
+        assert_eq!(matchups.scale(3, MatchupOutcome::Advantage), 6);
+        assert_eq!(matchups.scale(3, MatchupOutcome::Disadvantage), 0);
+    }
+}
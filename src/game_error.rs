@@ -0,0 +1,102 @@
+use crate::entities::CardId;
+
+#[derive(Debug, PartialEq)]
+pub enum GameError {
+    InvalidPhase,
+    NotOwned(CardId),
+    DuplicateCard(CardId),
+    TooManyCards {
+        played: usize,
+        max: u8,
+    },
+    NoActivePhilosopher,
+    NotAPhilosopher,
+    MissingCardId,
+    AssetLoad {
+        path: String,
+        reason: String,
+    },
+    SaveWrite {
+        path: String,
+        reason: String,
+    },
+    SaveLoad {
+        path: String,
+        reason: String,
+    },
+    SaveVersion {
+        path: String,
+        found: u32,
+        expected: u32,
+    },
+    UnknownPack(String),
+    CardNameCollision {
+        pack: String,
+        name: String,
+    },
+    InvalidDeck {
+        deck: String,
+        reason: String,
+    },
+    ReplayAssetsChanged(String),
+}
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::InvalidPhase => write!(f, "action not allowed in the current game phase"),
+            GameError::NotOwned(card_id) => {
+                write!(f, "card {} is not owned by the active player", card_id.0)
+            }
+            GameError::DuplicateCard(card_id) => {
+                write!(f, "card {} was played more than once", card_id.0)
+            }
+            GameError::TooManyCards { played, max } => {
+                write!(
+                    f,
+                    "{} cards played but only {} allowed per turn",
+                    played, max
+                )
+            }
+            GameError::NoActivePhilosopher => {
+                write!(f, "a philosopher must be in play or played this turn")
+            }
+            GameError::NotAPhilosopher => {
+                write!(f, "only philosopher cards can be played as philosophers")
+            }
+            GameError::MissingCardId => write!(f, "active philosopher has no card id"),
+            GameError::AssetLoad { path, reason } => {
+                write!(f, "couldn't load asset '{}': {}", path, reason)
+            }
+            GameError::SaveWrite { path, reason } => {
+                write!(f, "couldn't write save '{}': {}", path, reason)
+            }
+            GameError::SaveLoad { path, reason } => {
+                write!(f, "couldn't load save '{}': {}", path, reason)
+            }
+            GameError::SaveVersion {
+                path,
+                found,
+                expected,
+            } => write!(
+                f,
+                "save '{}' is version {} but this build only reads version {}",
+                path, found, expected
+            ),
+            GameError::UnknownPack(name) => write!(f, "no card pack named '{}'", name),
+            GameError::CardNameCollision { pack, name } => write!(
+                f,
+                "card pack '{}' adds '{}', which is already in the card set",
+                pack, name
+            ),
+            GameError::InvalidDeck { deck, reason } => {
+                write!(f, "deck '{}' can't be played: {}", deck, reason)
+            }
+            GameError::ReplayAssetsChanged(path) => write!(
+                f,
+                "replay '{}' was recorded with different cards or school matchups than the ones installed now",
+                path
+            ),
+        }
+    }
+}
+impl std::error::Error for GameError {}
@@ -1,4 +1,17 @@
+mod card_pack;
+mod decklist;
 mod game_board;
+mod game_event;
 mod helper_functions;
+mod replay;
+mod save_file;
 
-pub use game_board::{GameBoard, GameConfig, GamePhase};
+pub use card_pack::{available_packs, AvailablePacks, CardSet, PackManifest};
+pub use decklist::{available_decklists, decks_dir, DeckRules, Decklist};
+pub use game_board::{ConfigPreset, GameBoard, GameConfig, GameOverReason, GamePhase, Seat};
+pub use game_event::GameEvent;
+pub use helper_functions::{
+    get_action_cards, load_asset, load_yaml, set_asset_dir, OPENING_HAND_SIZE,
+};
+pub use replay::{available_replays, replays_dir, Replay};
+pub use save_file::{load_game, save_game, save_path};
@@ -0,0 +1,341 @@
+use crate::entities::{Action, Card, Philosopher};
+use crate::game_error::GameError;
+use crate::game_management::helper_functions::{
+    asset_dir, load_asset, load_asset_entries_from, load_yaml, scan_dir, user_dir, ACTIONS_FILE,
+    PHILOSOPHERS_FILE,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+const PACKS_DIR: &str = "packs";
+const MANIFEST_FILE: &str = "pack.yaml";
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PackManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+// pack files other than the manifest are optional
+fn load_optional<T: DeserializeOwned>(path: PathBuf) -> Result<Vec<T>, GameError> {
+    if path.exists() {
+        load_yaml(&path.to_string_lossy())
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// A folder of extra cards: a `pack.yaml` manifest plus optional philosophers and actions files.
+#[derive(Clone, Debug)]
+pub struct CardPack {
+    pub manifest: PackManifest,
+    pub philosophers: Vec<Philosopher>,
+    pub actions: Vec<Action>,
+}
+impl CardPack {
+    pub fn load(dir: &Path) -> Result<Self, GameError> {
+        Ok(CardPack {
+            manifest: load_yaml(&dir.join(MANIFEST_FILE).to_string_lossy())?,
+            philosophers: load_optional(dir.join(PHILOSOPHERS_FILE))?,
+            actions: load_optional(dir.join(ACTIONS_FILE))?,
+        })
+    }
+}
+
+/// The packs in the packs directory, each loaded on its own so a broken pack doesn't hide the rest.
+#[derive(Debug, Default)]
+pub struct AvailablePacks {
+    pub packs: Vec<CardPack>,
+    // the folder of each pack that couldn't be loaded and why
+    pub broken: Vec<(String, GameError)>,
+}
+
+pub fn available_packs() -> Result<AvailablePacks, GameError> {
+    load_packs_from(&user_dir(PACKS_DIR))
+}
+
+fn load_packs_from(dir: &Path) -> Result<AvailablePacks, GameError> {
+    let mut available = AvailablePacks::default();
+    for pack_dir in scan_dir(dir, |path| path.join(MANIFEST_FILE).exists())? {
+        // packs are enabled by name, so only the first folder to claim a name gets it
+        let loaded = CardPack::load(&pack_dir).and_then(|pack| {
+            let name = &pack.manifest.name;
+            if available.packs.iter().any(|p| p.manifest.name == *name) {
+                Err(GameError::AssetLoad {
+                    path: pack_dir.join(MANIFEST_FILE).to_string_lossy().into(),
+                    reason: format!("another pack is already named '{}'", name),
+                })
+            } else {
+                Ok(pack)
+            }
+        });
+        match loaded {
+            Ok(pack) => available.packs.push(pack),
+            Err(e) => {
+                let folder = pack_dir.file_name().unwrap_or_default();
+                available
+                    .broken
+                    .push((folder.to_string_lossy().into_owned(), e));
+            }
+        }
+    }
+    Ok(available)
+}
+
+// the entries that parsed, moving every error into `errors`
+fn keep_parsed<T>(
+    entries: Result<Vec<Result<T, GameError>>, GameError>,
+    errors: &mut Vec<GameError>,
+) -> Vec<T> {
+    match entries {
+        Ok(entries) => entries
+            .into_iter()
+            .filter_map(|entry| entry.map_err(|e| errors.push(e)).ok())
+            .collect(),
+        Err(e) => {
+            errors.push(e);
+            vec![]
+        }
+    }
+}
+
+/// Every philosopher and action a game can deal from: the base set plus any enabled packs.
+#[derive(Clone, Debug, Serialize)]
+pub struct CardSet {
+    pub philosophers: Vec<Philosopher>,
+    pub actions: Vec<Action>,
+}
+impl CardSet {
+    pub fn base() -> Result<Self, GameError> {
+        Ok(CardSet {
+            philosophers: load_asset(PHILOSOPHERS_FILE)?,
+            actions: load_asset(ACTIONS_FILE)?,
+        })
+    }
+
+    /// The base set without stopping at the first problem: every card that parses, plus an
+    /// error for each entry that doesn't and for each file that can't be read at all.
+    pub fn base_entries() -> (Self, Vec<GameError>) {
+        CardSet::base_entries_from(asset_dir().as_deref())
+    }
+
+    pub fn base_entries_from(asset_dir: Option<&Path>) -> (Self, Vec<GameError>) {
+        let mut errors = vec![];
+        let card_set = CardSet {
+            philosophers: keep_parsed(
+                load_asset_entries_from(asset_dir, PHILOSOPHERS_FILE),
+                &mut errors,
+            ),
+            actions: keep_parsed(
+                load_asset_entries_from(asset_dir, ACTIONS_FILE),
+                &mut errors,
+            ),
+        };
+        (card_set, errors)
+    }
+
+    /// The base set with the named packs from the packs directory merged on top, in order.
+    pub fn with_enabled_packs(pack_names: &[String]) -> Result<Self, GameError> {
+        let mut card_set = CardSet::base()?;
+        if pack_names.is_empty() {
+            return Ok(card_set);
+        }
+        let mut packs = available_packs()?.packs;
+        for pack_name in pack_names {
+            let i = packs
+                .iter()
+                .position(|pack| &pack.manifest.name == pack_name)
+                .ok_or_else(|| GameError::UnknownPack(pack_name.clone()))?;
+            card_set.add_pack(packs.remove(i))?;
+        }
+        Ok(card_set)
+    }
+
+    pub fn add_pack(&mut self, pack: CardPack) -> Result<(), GameError> {
+        let mut names: HashSet<String> = self.card_names().map(String::from).collect();
+        let new_names = pack
+            .philosophers
+            .iter()
+            .map(|philosopher| philosopher.name.as_str())
+            .chain(pack.actions.iter().map(|action| action.name()));
+        for name in new_names {
+            if !names.insert(name.to_string()) {
+                return Err(GameError::CardNameCollision {
+                    pack: pack.manifest.name,
+                    name: name.to_string(),
+                });
+            }
+        }
+        self.philosophers.extend(pack.philosophers);
+        self.actions.extend(pack.actions);
+        Ok(())
+    }
+
+    fn card_names(&self) -> impl Iterator<Item = &str> {
+        self.philosophers
+            .iter()
+            .map(|philosopher| philosopher.name.as_str())
+            .chain(self.actions.iter().map(|action| action.name()))
+    }
+
+    pub fn card(&self, name: &str) -> Option<Card> {
+        self.philosophers
+            .iter()
+            .find(|philosopher| philosopher.name == name)
+            .cloned()
+            .map(Card::Philosopher)
+            .or_else(|| {
+                self.actions
+                    .iter()
+                    .find(|action| action.name() == name)
+                    .cloned()
+                    .map(Card::Action)
+            })
+    }
+
+    pub fn philosopher_cards(&self) -> Vec<Card> {
+        self.philosophers
+            .iter()
+            .cloned()
+            .map(Card::Philosopher)
+            .collect()
+    }
+
+    pub fn action_cards(&self) -> Vec<Card> {
+        self.actions.iter().cloned().map(Card::Action).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{AbilityType, CoreSchool};
+    use crate::test_utils::get_temp_dir;
+
+    fn get_example_pack(name: &str, card_name: &str) -> CardPack {
+        CardPack {
+            manifest: PackManifest {
+                name: name.into(),
+                description: String::new(),
+            },
+            philosophers: vec![Philosopher::new(card_name.into(), CoreSchool::Skeptic, 8)],
+            actions: vec![Action::new(
+                format!("{} action", card_name),
+                "desc".into(),
+                CoreSchool::Skeptic,
+                AbilityType::Damage {
+                    damage: 1,
+                    duration: 0,
+                },
+                None,
+            )],
+        }
+    }
+
+    #[test]
+    fn test_add_pack_merges_cards() {
+        let mut card_set = CardSet::base().unwrap();
+        let num_philosophers = card_set.philosophers.len();
+        let num_actions = card_set.actions.len();
+        card_set
+            .add_pack(get_example_pack("extra", "Diogenes"))
+            .unwrap();
+        assert_eq!(card_set.philosophers.len(), num_philosophers + 1);
+        assert_eq!(card_set.actions.len(), num_actions + 1);
+    }
+
+    #[test]
+    fn test_add_pack_detects_name_collisions() {
+        let mut card_set = CardSet::base().unwrap();
+        card_set
+            .add_pack(get_example_pack("first", "Diogenes"))
+            .unwrap();
+        assert_eq!(
+            card_set.add_pack(get_example_pack("second", "Diogenes")),
+            Err(GameError::CardNameCollision {
+                pack: "second".into(),
+                name: "Diogenes".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_packs_from_dir() {
+        let dir = get_temp_dir("packs");
+        let pack_dir = dir.join("cynics");
+        std::fs::create_dir_all(&pack_dir).unwrap();
+        std::fs::write(
+            pack_dir.join(MANIFEST_FILE),
+            "name: Cynics\ndescription: Dogs of philosophy\n",
+        )
+        .unwrap();
+        std::fs::write(
+            pack_dir.join(PHILOSOPHERS_FILE),
+            "- name: Diogenes\n  school: Skeptic\n  starting_health: 9\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.join("not_a_pack")).unwrap();
+        let packs = load_packs_from(&dir).unwrap().packs;
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(packs.len(), 1);
+        assert_eq!(packs[0].manifest.name, "Cynics");
+        assert_eq!(packs[0].philosophers[0].name, "Diogenes");
+        assert!(packs[0].actions.is_empty());
+    }
+
+    #[test]
+    fn test_broken_pack_does_not_hide_the_others() {
+        let dir = get_temp_dir("packs");
+        for (folder, school) in [("cynics", "Skeptic"), ("stoics", "Stoic")] {
+            let pack_dir = dir.join(folder);
+            std::fs::create_dir_all(&pack_dir).unwrap();
+            std::fs::write(pack_dir.join(MANIFEST_FILE), format!("name: {}\n", folder)).unwrap();
+            std::fs::write(
+                pack_dir.join(PHILOSOPHERS_FILE),
+                format!("- name: Zeno\n  school: {}\n  starting_health: 9\n", school),
+            )
+            .unwrap();
+        }
+        let available = load_packs_from(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(available.packs.len(), 1);
+        assert_eq!(available.packs[0].manifest.name, "cynics");
+        assert_eq!(available.broken.len(), 1);
+        let (folder, error) = &available.broken[0];
+        assert_eq!(folder, "stoics");
+        assert!(matches!(
+            error,
+            GameError::AssetLoad { reason, .. } if reason.contains("unknown variant `Stoic`")
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_pack_name_is_broken() {
+        let dir = get_temp_dir("packs");
+        for folder in ["cynics", "dogs"] {
+            let pack_dir = dir.join(folder);
+            std::fs::create_dir_all(&pack_dir).unwrap();
+            std::fs::write(pack_dir.join(MANIFEST_FILE), "name: Cynics\n").unwrap();
+        }
+        let available = load_packs_from(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(available.packs.len(), 1);
+        assert_eq!(available.broken.len(), 1);
+        let (folder, error) = &available.broken[0];
+        assert_eq!(folder, "dogs");
+        assert!(matches!(
+            error,
+            GameError::AssetLoad { reason, .. } if reason == "another pack is already named 'Cynics'"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_pack() {
+        assert_eq!(
+            CardSet::with_enabled_packs(&["no such pack".into()]).unwrap_err(),
+            GameError::UnknownPack("no such pack".into())
+        );
+    }
+}
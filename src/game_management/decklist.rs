@@ -0,0 +1,389 @@
+use crate::entities::Card;
+use crate::game_error::GameError;
+use crate::game_management::card_pack::CardSet;
+use crate::game_management::helper_functions::{
+    is_yaml_file, load_asset, load_yaml, scan_dir, user_dir, DECK_RULES_FILE, OPENING_HAND_SIZE,
+};
+use crate::game_management::save_file::write_yaml;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const DECKS_DIR: &str = "decks";
+
+/// Construction rules every decklist has to follow.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeckRules {
+    pub min_deck_size: usize,
+    pub max_deck_size: usize,
+    pub max_copies: usize,
+    pub min_philosophers: usize,
+}
+impl DeckRules {
+    /// Loads the rules from `deck_rules.yaml`, so they can be changed from the asset directory.
+    pub fn load() -> Result<Self, GameError> {
+        let rules: DeckRules = load_asset(DECK_RULES_FILE)?;
+        if rules.min_deck_size > rules.max_deck_size {
+            return Err(GameError::AssetLoad {
+                path: DECK_RULES_FILE.into(),
+                reason: format!(
+                    "min_deck_size {} is larger than max_deck_size {}",
+                    rules.min_deck_size, rules.max_deck_size
+                ),
+            });
+        }
+        Ok(rules)
+    }
+}
+// matches the built-in deck_rules.yaml
+impl Default for DeckRules {
+    fn default() -> Self {
+        DeckRules {
+            min_deck_size: 10,
+            max_deck_size: 30,
+            max_copies: 3,
+            min_philosophers: 2,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DecklistEntry {
+    pub card: String,
+    pub count: usize,
+}
+
+/// A named list of cards and how many copies of each a player brings to a game.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Decklist {
+    pub name: String,
+    pub cards: Vec<DecklistEntry>,
+}
+impl Decklist {
+    pub fn load(path: &Path) -> Result<Self, GameError> {
+        load_yaml(&path.to_string_lossy())
+    }
+
+    pub fn new(name: String) -> Self {
+        Decklist {
+            name,
+            cards: vec![],
+        }
+    }
+
+    /// Where the decklist saves to in `dir`, named after the deck.
+    pub fn path_in(&self, dir: &Path) -> PathBuf {
+        let file_name: String = self
+            .name
+            .trim()
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        dir.join(format!("{}.yaml", file_name))
+    }
+
+    /// Saves the decklist to `path_in(dir)`, replacing whatever is there, and returns the path.
+    pub fn save_to(&self, dir: &Path) -> Result<PathBuf, GameError> {
+        let path = self.path_in(dir);
+        // an empty name would save as `.yaml`, which the decks directory skips
+        if self.name.trim().is_empty() {
+            return Err(GameError::SaveWrite {
+                path: path.to_string_lossy().into(),
+                reason: "the deck has no name".into(),
+            });
+        }
+        std::fs::create_dir_all(dir).map_err(|e| GameError::SaveWrite {
+            path: dir.to_string_lossy().into(),
+            reason: e.to_string(),
+        })?;
+        write_yaml(&path.to_string_lossy(), self)?;
+        Ok(path)
+    }
+
+    pub fn count(&self, card_name: &str) -> usize {
+        self.cards
+            .iter()
+            .filter(|entry| entry.card == card_name)
+            .map(|entry| entry.count)
+            .sum()
+    }
+
+    pub fn add(&mut self, card_name: &str) {
+        match self.cards.iter_mut().find(|entry| entry.card == card_name) {
+            Some(entry) => entry.count += 1,
+            None => self.cards.push(DecklistEntry {
+                card: card_name.into(),
+                count: 1,
+            }),
+        }
+    }
+
+    pub fn remove(&mut self, card_name: &str) {
+        if let Some(i) = self.cards.iter().position(|entry| entry.card == card_name) {
+            if self.cards[i].count <= 1 {
+                self.cards.remove(i);
+            } else {
+                self.cards[i].count -= 1;
+            }
+        }
+    }
+
+    pub fn num_cards(&self) -> usize {
+        self.cards.iter().map(|entry| entry.count).sum()
+    }
+
+    // copies per card name, adding up entries that repeat a name
+    fn card_counts(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = vec![];
+        for entry in &self.cards {
+            match counts.iter_mut().find(|(name, _)| *name == entry.card) {
+                Some((_, count)) => *count += entry.count,
+                None => counts.push((&entry.card, entry.count)),
+            }
+        }
+        counts
+    }
+
+    /// Every way the decklist breaks the rules or names a card missing from the set.
+    pub fn rule_violations(&self, card_set: &CardSet, rules: &DeckRules) -> Vec<String> {
+        let mut violations = vec![];
+        let mut num_philosophers = 0;
+        for entry in self.cards.iter().filter(|entry| entry.count == 0) {
+            violations.push(format!("'{}' is listed with 0 copies", entry.card));
+        }
+        for (name, count) in self.card_counts() {
+            match card_set.card(name) {
+                Some(Card::Philosopher(_)) => num_philosophers += count,
+                Some(_) => {}
+                None => violations.push(format!("unknown card '{}'", name)),
+            }
+            if count > rules.max_copies {
+                violations.push(format!(
+                    "{} copies of '{}' but at most {} are allowed",
+                    count, name, rules.max_copies
+                ));
+            }
+        }
+
+        // one card is the starting philosopher and the opening hand is drawn from the rest
+        let min_deck_size = rules.min_deck_size.max(usize::from(OPENING_HAND_SIZE) + 1);
+        let num_cards = self.num_cards();
+        if num_cards < min_deck_size {
+            violations.push(format!(
+                "{} cards but at least {} are needed",
+                num_cards, min_deck_size
+            ));
+        } else if num_cards > rules.max_deck_size {
+            violations.push(format!(
+                "{} cards but at most {} are allowed",
+                num_cards, rules.max_deck_size
+            ));
+        }
+        let min_philosophers = rules.min_philosophers.max(1);
+        if num_philosophers < min_philosophers {
+            violations.push(format!(
+                "{} philosophers but at least {} are needed",
+                num_philosophers, min_philosophers
+            ));
+        }
+        violations
+    }
+
+    /// Every copy of every card in the decklist, once it's checked against the rules.
+    pub fn cards(&self, card_set: &CardSet, rules: &DeckRules) -> Result<Vec<Card>, GameError> {
+        let violations = self.rule_violations(card_set, rules);
+        if !violations.is_empty() {
+            return Err(GameError::InvalidDeck {
+                deck: self.name.clone(),
+                reason: violations.join("; "),
+            });
+        }
+        let mut cards = vec![];
+        for entry in &self.cards {
+            if let Some(card) = card_set.card(&entry.card) {
+                cards.extend(std::iter::repeat_n(card, entry.count));
+            }
+        }
+        Ok(cards)
+    }
+}
+
+/// The cards one seat plays with: its decklist, or one of every card in the set without one.
+pub(super) fn deck_cards(
+    card_set: &CardSet,
+    decklist: Option<&Decklist>,
+    rules: &DeckRules,
+) -> Result<Vec<Card>, GameError> {
+    match decklist {
+        Some(decklist) => decklist.cards(card_set, rules),
+        None => {
+            let mut cards = card_set.philosopher_cards();
+            cards.extend(card_set.action_cards());
+            Ok(cards)
+        }
+    }
+}
+
+pub fn decks_dir() -> PathBuf {
+    user_dir(DECKS_DIR)
+}
+
+/// Every saved decklist with the file it was loaded from.
+pub fn available_decklists() -> Result<Vec<(PathBuf, Decklist)>, GameError> {
+    load_decklists_from(&decks_dir())
+}
+
+fn load_decklists_from(dir: &Path) -> Result<Vec<(PathBuf, Decklist)>, GameError> {
+    scan_dir(dir, is_yaml_file)?
+        .into_iter()
+        .map(|path| Decklist::load(&path).map(|decklist| (path, decklist)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir;
+
+    fn entry(card: &str, count: usize) -> DecklistEntry {
+        DecklistEntry {
+            card: card.into(),
+            count,
+        }
+    }
+
+    fn get_example_decklist() -> Decklist {
+        Decklist {
+            name: "Doubters".into(),
+            cards: vec![
+                entry("Pyrrho", 2),
+                entry("Plato", 1),
+                entry("Radical Doubt", 3),
+                entry("Suspension of Belief", 3),
+                entry("Abstract thought", 2),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_default_rules_match_built_in_file() {
+        assert_eq!(DeckRules::load().unwrap(), DeckRules::default());
+    }
+
+    #[test]
+    fn test_example_decklist_follows_default_rules() {
+        let card_set = CardSet::base().unwrap();
+        let decklist = get_example_decklist();
+        assert_eq!(
+            decklist.rule_violations(&card_set, &DeckRules::default()),
+            Vec::<String>::new()
+        );
+        let cards = decklist.cards(&card_set, &DeckRules::default()).unwrap();
+        assert_eq!(cards.len(), 11);
+        assert_eq!(
+            cards
+                .iter()
+                .filter(|card| matches!(card, Card::Philosopher(_)))
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_rule_violations() {
+        let card_set = CardSet::base().unwrap();
+        let decklist = Decklist {
+            name: "Broken".into(),
+            cards: vec![
+                entry("Plato", 1),
+                entry("Radical Doubt", 2),
+                entry("Radical Doubt", 2),
+                entry("Socrates", 1),
+            ],
+        };
+        assert_eq!(
+            decklist.rule_violations(&card_set, &DeckRules::default()),
+            [
+                "4 copies of 'Radical Doubt' but at most 3 are allowed",
+                "unknown card 'Socrates'",
+                "6 cards but at least 10 are needed",
+                "1 philosophers but at least 2 are needed",
+            ]
+        );
+        assert!(matches!(
+            decklist.cards(&card_set, &DeckRules::default()),
+            Err(GameError::InvalidDeck { deck, .. }) if deck == "Broken"
+        ));
+    }
+
+    #[test]
+    fn test_add_and_remove_cards() {
+        let mut decklist = Decklist::new("Empty".into());
+        decklist.add("Plato");
+        decklist.add("Plato");
+        decklist.add("Radical Doubt");
+        decklist.remove("Radical Doubt");
+        decklist.remove("Aristotle");
+        assert_eq!(decklist.count("Plato"), 2);
+        assert_eq!(decklist.cards, [entry("Plato", 2)]);
+    }
+
+    #[test]
+    fn test_zero_count_entries() {
+        let card_set = CardSet::base().unwrap();
+        let mut decklist = get_example_decklist();
+        decklist.cards.push(entry("Aristotle", 0));
+        assert_eq!(
+            decklist.rule_violations(&card_set, &DeckRules::default()),
+            ["'Aristotle' is listed with 0 copies"]
+        );
+        decklist.remove("Aristotle");
+        assert_eq!(decklist.cards, get_example_decklist().cards);
+    }
+
+    #[test]
+    fn test_save_names_file_after_deck() {
+        let dir = get_temp_dir("decks");
+        let decklist = Decklist {
+            name: "Doubt & Reason".into(),
+            ..get_example_decklist()
+        };
+        let path = decklist.save_to(&dir).unwrap();
+        let loaded = Decklist::load(&path);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(path.file_name().unwrap(), "doubt___reason.yaml");
+        assert_eq!(loaded.unwrap(), decklist);
+    }
+
+    #[test]
+    fn test_save_rejects_empty_name() {
+        let dir = get_temp_dir("decks");
+        let decklist = Decklist {
+            name: "  ".into(),
+            ..get_example_decklist()
+        };
+        assert!(matches!(
+            decklist.save_to(&dir),
+            Err(GameError::SaveWrite { reason, .. }) if reason == "the deck has no name"
+        ));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_load_decklists_from_dir() {
+        let dir = get_temp_dir("decks");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("doubters.yaml"),
+            serde_yaml::to_string(&get_example_decklist()).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a decklist").unwrap();
+        let decklists = load_decklists_from(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            decklists,
+            [(dir.join("doubters.yaml"), get_example_decklist())]
+        );
+    }
+}
@@ -1,27 +1,194 @@
-use crate::entities::{AbilityType, Action, Card, Effect, InPlayPhilosopher, Philosopher};
+use crate::entities::{
+    AbilityType, Action, ActiveEffect, Card, CardId, CardIdGenerator, CardInstance, Effect,
+    EffectTarget, InPlayPhilosopher, MatchupOutcome, SchoolMatchups,
+};
+use crate::game_error::GameError;
+use crate::game_management::card_pack::CardSet;
+use crate::game_management::decklist::{deck_cards, DeckRules, Decklist};
+use crate::game_management::game_event::{EventLog, GameEvent};
 use crate::game_management::helper_functions;
-use crate::player::{PlayerHand, RemainingDeck};
+use crate::player::{DiscardPile, Graveyard, PlayerHand, RemainingDeck};
+use rand::{rng, rngs::StdRng, Rng, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum GamePhase {
     Player1Turn,
     Player2Turn,
     GameOver,
 }
+impl std::fmt::Display for GamePhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.active_seat() {
+            Some(seat) => write!(f, "{}'s turn", seat),
+            None => write!(f, "game over"),
+        }
+    }
+}
+impl GamePhase {
+    pub fn active_seat(&self) -> Option<Seat> {
+        match self {
+            GamePhase::Player1Turn => Some(Seat::Player1),
+            GamePhase::Player2Turn => Some(Seat::Player2),
+            GamePhase::GameOver => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Seat {
+    Player1,
+    Player2,
+}
+impl Seat {
+    pub fn other(&self) -> Seat {
+        match self {
+            Seat::Player1 => Seat::Player2,
+            Seat::Player2 => Seat::Player1,
+        }
+    }
+}
+impl std::fmt::Display for Seat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Seat::Player1 => write!(f, "Player 1"),
+            Seat::Player2 => write!(f, "Player 2"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum OutOfCardsRule {
+    Reshuffle,
+    Fatigue,
+    Lose,
+}
+impl std::fmt::Display for OutOfCardsRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutOfCardsRule::Reshuffle => write!(f, "reshuffle"),
+            OutOfCardsRule::Fatigue => write!(f, "fatigue"),
+            OutOfCardsRule::Lose => write!(f, "lose"),
+        }
+    }
+}
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum GameOverReason {
+    PhilosopherDefeated { loser: Seat },
+    OutOfCards { loser: Seat, rule: OutOfCardsRule },
+}
+impl std::fmt::Display for GameOverReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameOverReason::PhilosopherDefeated { loser } => {
+                write!(f, "{}'s philosopher was defeated", loser)
+            }
+            GameOverReason::OutOfCards { loser, rule } => {
+                write!(
+                    f,
+                    "{} ran out of cards (out-of-cards rule: {})",
+                    loser, rule
+                )
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigPreset {
+    Standard,
+    Quick,
+    Endurance,
+}
+impl std::fmt::Display for ConfigPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigPreset::Standard => write!(f, "Standard"),
+            ConfigPreset::Quick => write!(f, "Quick"),
+            ConfigPreset::Endurance => write!(f, "Endurance"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GameConfig {
     num_cards_played_per_turn: u8,
     num_cards_drawn_per_turn: u8,
     max_cards_in_hand: u8,
+    out_of_cards_rule: OutOfCardsRule,
+    // names of the card packs merged on top of the base set
+    #[serde(default)]
+    packs: Vec<String>,
+    #[serde(default)]
+    deck_rules: DeckRules,
+    // without a decklist a seat plays one copy of every card in the set
+    #[serde(default)]
+    player_1_decklist: Option<Decklist>,
+    #[serde(default)]
+    player_2_decklist: Option<Decklist>,
+    // every random choice in a game is derived from this, so a seed and its moves replay exactly
+    seed: u64,
 }
 impl GameConfig {
+    pub fn from_preset(preset: ConfigPreset) -> Self {
+        match preset {
+            ConfigPreset::Standard => Self::default(),
+            ConfigPreset::Quick => Self {
+                num_cards_drawn_per_turn: 3,
+                out_of_cards_rule: OutOfCardsRule::Lose,
+                ..Self::default()
+            },
+            ConfigPreset::Endurance => Self {
+                num_cards_played_per_turn: 2,
+                num_cards_drawn_per_turn: 1,
+                max_cards_in_hand: 6,
+                out_of_cards_rule: OutOfCardsRule::Fatigue,
+                ..Self::default()
+            },
+        }
+    }
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+    pub fn with_packs(mut self, packs: Vec<String>) -> Self {
+        self.packs = packs;
+        self
+    }
+    pub fn packs(&self) -> &[String] {
+        &self.packs
+    }
+    pub fn with_decklist(mut self, seat: Seat, decklist: Option<Decklist>) -> Self {
+        match seat {
+            Seat::Player1 => self.player_1_decklist = decklist,
+            Seat::Player2 => self.player_2_decklist = decklist,
+        }
+        self
+    }
+    pub fn with_deck_rules(mut self, deck_rules: DeckRules) -> Self {
+        self.deck_rules = deck_rules;
+        self
+    }
+    pub fn deck_rules(&self) -> &DeckRules {
+        &self.deck_rules
+    }
     pub fn max_cards_in_hand(&self) -> u8 {
         self.max_cards_in_hand
     }
     pub fn max_cards_played_per_turn(&self) -> u8 {
         self.num_cards_played_per_turn
     }
+    pub fn cards_drawn_per_turn(&self) -> u8 {
+        self.num_cards_drawn_per_turn
+    }
+    pub fn out_of_cards_rule(&self) -> OutOfCardsRule {
+        self.out_of_cards_rule
+    }
 }
 impl Default for GameConfig {
     fn default() -> Self {
@@ -29,92 +196,455 @@ impl Default for GameConfig {
             num_cards_played_per_turn: 3,
             num_cards_drawn_per_turn: 2,
             max_cards_in_hand: 7,
+            out_of_cards_rule: OutOfCardsRule::Reshuffle,
+            packs: vec![],
+            deck_rules: DeckRules::default(),
+            player_1_decklist: None,
+            player_2_decklist: None,
+            seed: rng().next_u64(),
         }
     }
 }
 
-#[derive(Debug)]
+// the parts of a philosopher that the order of a turn's cards can change
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct PhilosopherOutcome {
+    id: CardId,
+    remaining_health: u8,
+    effects: Vec<ActiveEffect>,
+}
+impl PhilosopherOutcome {
+    fn new(id: CardId, philosopher: &InPlayPhilosopher) -> Self {
+        let mut effects = philosopher.effects.clone();
+        effects.sort();
+        PhilosopherOutcome {
+            id,
+            remaining_health: philosopher.remaining_health(),
+            effects,
+        }
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct SeatOutcome {
+    active_philosopher: Option<PhilosopherOutcome>,
+    // philosophers swapped out earlier keep their health and effects in the hand
+    benched_philosophers: Vec<PhilosopherOutcome>,
+    hand_ids: Vec<CardId>,
+    discard_ids: Vec<CardId>,
+}
+impl SeatOutcome {
+    fn of(board: &GameBoard, seat: &Seat) -> Self {
+        let hand = board.hand(seat);
+        let active_philosopher = hand
+            .active_philosopher
+            .as_ref()
+            .zip(hand.active_philosopher_id)
+            .map(|(philosopher, id)| PhilosopherOutcome::new(id, philosopher));
+        let mut benched_philosophers: Vec<PhilosopherOutcome> = hand
+            .inactive_cards
+            .iter()
+            .filter_map(|card| match &card.card {
+                Card::InPlayPhilosopher(philosopher) => {
+                    Some(PhilosopherOutcome::new(card.id, philosopher))
+                }
+                _ => None,
+            })
+            .collect();
+        benched_philosophers.sort_by_key(|philosopher| philosopher.id);
+        let mut hand_ids: Vec<CardId> = hand.inactive_cards.iter().map(|c| c.id).collect();
+        hand_ids.sort();
+        let mut discard_ids: Vec<CardId> = board
+            .discard_pile(seat)
+            .cards()
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        discard_ids.sort();
+        SeatOutcome {
+            active_philosopher,
+            benched_philosophers,
+            hand_ids,
+            discard_ids,
+        }
+    }
+}
+
+/// Both seats after a turn, so that orderings of the same cards with the same result count once.
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct TurnOutcome {
+    player_1: SeatOutcome,
+    player_2: SeatOutcome,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GameBoard {
     pub player_1_hand: PlayerHand,
     player_1_deck: RemainingDeck,
+    player_1_discard_pile: DiscardPile,
+    player_1_graveyard: Graveyard,
     pub player_2_hand: PlayerHand,
     player_2_deck: RemainingDeck,
+    player_2_discard_pile: DiscardPile,
+    player_2_graveyard: Graveyard,
+    player_1_fatigue: u8,
+    player_2_fatigue: u8,
     game_phase: GamePhase,
+    game_over_reason: Option<GameOverReason>,
     pub game_config: GameConfig,
+    // shared so that cloning the board for AI search stays cheap
+    school_matchups: Rc<SchoolMatchups>,
+    event_log: EventLog,
+    #[serde(default)]
+    turn_history: Vec<Vec<CardId>>,
+    // the philosophers each seat was dealt, before anyone chooses what to play
+    #[serde(default)]
+    player_1_starting_philosopher: Option<String>,
+    #[serde(default)]
+    player_2_starting_philosopher: Option<String>,
 }
 impl GameBoard {
-    pub fn new(game_config: Option<GameConfig>) -> Self {
+    pub fn new(game_config: Option<GameConfig>) -> Result<Self, GameError> {
         let config: GameConfig = game_config.unwrap_or_default();
-        let (p1_start_hand, p1_deck) = helper_functions::get_intial_deck(&config.max_cards_in_hand)
-            .expect("Can't get player1 hand");
-        let (p2_start_hand, p2_deck) = helper_functions::get_intial_deck(&config.max_cards_in_hand)
-            .expect("Can't get player2 hand");
-        GameBoard {
+        let mut seeds = StdRng::seed_from_u64(config.seed);
+        let mut card_ids = CardIdGenerator::default();
+        let card_set = CardSet::with_enabled_packs(&config.packs)?;
+        let p1_cards = deck_cards(
+            &card_set,
+            config.player_1_decklist.as_ref(),
+            &config.deck_rules,
+        )?;
+        let p2_cards = deck_cards(
+            &card_set,
+            config.player_2_decklist.as_ref(),
+            &config.deck_rules,
+        )?;
+        let (p1_start_hand, p1_deck) = helper_functions::get_intial_deck(
+            p1_cards,
+            &config.max_cards_in_hand,
+            &mut card_ids,
+            &mut seeds,
+        )?;
+        let (p2_start_hand, p2_deck) = helper_functions::get_intial_deck(
+            p2_cards,
+            &config.max_cards_in_hand,
+            &mut card_ids,
+            &mut seeds,
+        )?;
+        let school_matchups = helper_functions::get_school_matchups()?;
+        // the starting philosopher is dealt before the opening hand
+        let starting_philosopher =
+            |hand: &PlayerHand| Some(hand.inactive_cards.first()?.card.name().to_string());
+        Ok(GameBoard {
+            player_1_starting_philosopher: starting_philosopher(&p1_start_hand),
+            player_2_starting_philosopher: starting_philosopher(&p2_start_hand),
             player_1_hand: p1_start_hand,
             player_1_deck: p1_deck,
+            player_1_discard_pile: DiscardPile::default(),
+            player_1_graveyard: Graveyard::default(),
             player_2_hand: p2_start_hand,
             player_2_deck: p2_deck,
+            player_2_discard_pile: DiscardPile::default(),
+            player_2_graveyard: Graveyard::default(),
+            player_1_fatigue: 0,
+            player_2_fatigue: 0,
             game_phase: GamePhase::Player1Turn,
+            game_over_reason: None,
             game_config: config,
-        }
+            school_matchups: Rc::new(school_matchups),
+            event_log: EventLog::default(),
+            turn_history: vec![],
+        })
     }
 
     pub fn game_phase(&self) -> &GamePhase {
         &self.game_phase
     }
 
+    pub fn game_over_reason(&self) -> Option<&GameOverReason> {
+        self.game_over_reason.as_ref()
+    }
+
+    /// Why play can't carry on from this board, such as a finished game or a hand that breaks
+    /// the config, for boards that come from outside rather than from playing turns.
+    pub fn unplayable_reason(&self) -> Option<String> {
+        if self.game_phase == GamePhase::GameOver {
+            return Some("the game is already over".into());
+        }
+        if self.game_over_reason.is_some() {
+            return Some("the game has a result but is still in progress".into());
+        }
+        for seat in [Seat::Player1, Seat::Player2] {
+            let hand = self.hand(&seat);
+            if hand.active_philosopher.is_some() != hand.active_philosopher_id.is_some() {
+                return Some(format!(
+                    "{}'s active philosopher doesn't match its card id",
+                    seat
+                ));
+            }
+            if hand.inactive_cards.len() > usize::from(self.game_config.max_cards_in_hand) {
+                return Some(format!(
+                    "{} holds {} cards but the hand size is {}",
+                    seat,
+                    hand.inactive_cards.len(),
+                    self.game_config.max_cards_in_hand
+                ));
+            }
+        }
+        None
+    }
+
+    pub fn starting_philosopher(&self, seat: &Seat) -> Option<&str> {
+        match seat {
+            Seat::Player1 => self.player_1_starting_philosopher.as_deref(),
+            Seat::Player2 => self.player_2_starting_philosopher.as_deref(),
+        }
+    }
+
+    pub fn event_log(&self) -> &EventLog {
+        &self.event_log
+    }
+
+    fn log_event(&mut self, event: GameEvent) {
+        let turn = self.turn_history.len();
+        self.event_log.push(turn, event);
+    }
+
+    fn log_death(&mut self, seat: Seat, was_dead: bool) {
+        if let Some(p) = &self.hand(&seat).active_philosopher {
+            if !was_dead && p.is_dead() {
+                let philosopher = p.philosopher.name.clone();
+                self.log_event(GameEvent::PhilosopherDied { seat, philosopher });
+            }
+        }
+    }
+
+    pub fn turn_history(&self) -> &[Vec<CardId>] {
+        &self.turn_history
+    }
+
+    pub fn hand(&self, seat: &Seat) -> &PlayerHand {
+        match seat {
+            Seat::Player1 => &self.player_1_hand,
+            Seat::Player2 => &self.player_2_hand,
+        }
+    }
+
+    pub fn philosopher_health(&self, seat: &Seat) -> u8 {
+        self.hand(seat)
+            .active_philosopher
+            .as_ref()
+            .map_or(0, |p| p.remaining_health())
+    }
+
+    pub fn remaining_deck(&self, seat: &Seat) -> &RemainingDeck {
+        match seat {
+            Seat::Player1 => &self.player_1_deck,
+            Seat::Player2 => &self.player_2_deck,
+        }
+    }
+
+    pub fn discard_pile(&self, seat: &Seat) -> &DiscardPile {
+        match seat {
+            Seat::Player1 => &self.player_1_discard_pile,
+            Seat::Player2 => &self.player_2_discard_pile,
+        }
+    }
+
+    pub fn graveyard(&self, seat: &Seat) -> &Graveyard {
+        match seat {
+            Seat::Player1 => &self.player_1_graveyard,
+            Seat::Player2 => &self.player_2_graveyard,
+        }
+    }
+
+    fn discard_card(&mut self, card: CardInstance) -> Result<(), GameError> {
+        match self
+            .game_phase
+            .active_seat()
+            .ok_or(GameError::InvalidPhase)?
+        {
+            Seat::Player1 => self.player_1_discard_pile.add_card(card),
+            Seat::Player2 => self.player_2_discard_pile.add_card(card),
+        }
+        Ok(())
+    }
+
+    fn bury_dead_philosophers(&mut self) -> Result<(), GameError> {
+        for (hand, graveyard) in [
+            (&mut self.player_1_hand, &mut self.player_1_graveyard),
+            (&mut self.player_2_hand, &mut self.player_2_graveyard),
+        ] {
+            for philosopher in hand.remove_dead_philosophers() {
+                graveyard.add_philosopher(philosopher)?;
+            }
+        }
+        Ok(())
+    }
+
     fn update_game_phase(&mut self) {
         self.check_for_game_over();
-        match self.game_phase {
-            GamePhase::Player1Turn => self.game_phase = GamePhase::Player2Turn,
-            GamePhase::Player2Turn => self.game_phase = GamePhase::Player1Turn,
-            GamePhase::GameOver => (),
-        }
+        let next_phase = match self.game_phase {
+            GamePhase::Player1Turn => GamePhase::Player2Turn,
+            GamePhase::Player2Turn => GamePhase::Player1Turn,
+            GamePhase::GameOver => return,
+        };
+        self.game_phase = next_phase.clone();
+        self.log_event(GameEvent::PhaseChanged { phase: next_phase });
+    }
+
+    fn end_game(&mut self, reason: GameOverReason) {
+        self.game_phase = GamePhase::GameOver;
+        self.game_over_reason = Some(reason.clone());
+        self.log_event(GameEvent::GameOver { reason });
     }
 
     fn check_for_game_over(&mut self) {
+        let Some(active_seat) = self.game_phase.active_seat() else {
+            return;
+        };
         let (inactive_hand, _) = self
             .inactive_player_data()
             .expect("can't get inactive player data to check game over");
         if let Some(p) = &inactive_hand.active_philosopher {
             if p.is_dead() {
-                self.game_phase = GamePhase::GameOver
+                self.end_game(GameOverReason::PhilosopherDefeated {
+                    loser: active_seat.other(),
+                });
             }
         }
     }
 
-    fn apply_effects(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn apply_effects(&mut self) -> Result<(), GameError> {
+        let target = self
+            .game_phase
+            .active_seat()
+            .ok_or(GameError::InvalidPhase)?
+            .other();
         let (inactive_hand, _) = self
             .inactive_player_data()
             .expect("can't get inactive player for applying effects");
-        if let Some(p) = inactive_hand.active_philosopher.as_mut() {
-            p.apply_existing_effects();
+        let Some(p) = inactive_hand.active_philosopher.as_mut() else {
+            return Ok(());
+        };
+        let was_dead = p.is_dead();
+        let philosopher = p.philosopher.name.clone();
+        for (active_effect, amount) in p.apply_existing_effects() {
+            self.log_event(GameEvent::EffectTicked {
+                target,
+                philosopher: philosopher.clone(),
+                source: active_effect.source,
+                effect: active_effect.effect,
+                amount,
+            });
         }
+        self.log_death(target, was_dead);
         Ok(())
     }
 
-    fn draw_cards_for_next_player(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn draw_cards_for_next_player(&mut self) -> Result<(), GameError> {
         // extract value below to avoid borrow checker issues with mutable references to self
         let num_cards_per_turn = self.game_config.num_cards_drawn_per_turn;
+        let next_seat = self
+            .game_phase
+            .active_seat()
+            .ok_or(GameError::InvalidPhase)?
+            .other();
         let (inactive_hand, inactive_deck) = self.inactive_player_data()?;
-        let num_cards_to_draw = num_cards_per_turn.min(inactive_hand.num_available_slots_in_hand());
-        let new_cards = inactive_deck.draw_new_cards(num_cards_to_draw)?;
-        inactive_hand.add_cards_to_hand(new_cards)?;
+        let num_cards_to_draw =
+            usize::from(num_cards_per_turn).min(inactive_hand.num_available_slots_in_hand());
+        let mut new_cards = inactive_deck.draw_new_cards(num_cards_to_draw)?;
+        let shortfall = num_cards_to_draw - new_cards.len();
+        if shortfall > 0 {
+            new_cards.extend(self.handle_out_of_cards(next_seat, shortfall)?);
+        }
+        // the out-of-cards rule may have ended the game, so look the hand up by seat
+        let next_hand = match next_seat {
+            Seat::Player1 => &mut self.player_1_hand,
+            Seat::Player2 => &mut self.player_2_hand,
+        };
+        let count = new_cards.len();
+        next_hand.add_cards_to_hand(new_cards)?;
+        if count > 0 {
+            self.log_event(GameEvent::CardsDrawn {
+                seat: next_seat,
+                count,
+            });
+        }
         Ok(())
     }
 
-    pub fn process_turn(&mut self, cards: Vec<Card>) -> Result<(), Box<dyn std::error::Error>> {
+    fn handle_out_of_cards(
+        &mut self,
+        seat: Seat,
+        shortfall: usize,
+    ) -> Result<Vec<CardInstance>, GameError> {
+        let rule = self.game_config.out_of_cards_rule;
+        let (hand, deck, discard_pile, fatigue) = match seat {
+            Seat::Player1 => (
+                &mut self.player_1_hand,
+                &mut self.player_1_deck,
+                &mut self.player_1_discard_pile,
+                &mut self.player_1_fatigue,
+            ),
+            Seat::Player2 => (
+                &mut self.player_2_hand,
+                &mut self.player_2_deck,
+                &mut self.player_2_discard_pile,
+                &mut self.player_2_fatigue,
+            ),
+        };
+        match rule {
+            OutOfCardsRule::Reshuffle => {
+                deck.shuffle_in(discard_pile.take_all());
+                deck.draw_new_cards(shortfall)
+            }
+            OutOfCardsRule::Fatigue => {
+                *fatigue = fatigue.saturating_add(1);
+                let fatigue_damage = *fatigue;
+                if let Some(p) = hand.active_philosopher.as_mut() {
+                    let was_dead = p.is_dead();
+                    p.apply_direct_damage(fatigue_damage);
+                    let is_dead = p.is_dead();
+                    let philosopher = p.philosopher.name.clone();
+                    self.log_event(GameEvent::DamageDealt {
+                        target: seat,
+                        philosopher,
+                        source: "Fatigue".into(),
+                        base_amount: fatigue_damage,
+                        amount: fatigue_damage,
+                        matchup: MatchupOutcome::Neutral,
+                    });
+                    self.log_death(seat, was_dead);
+                    if is_dead {
+                        self.end_game(GameOverReason::OutOfCards { loser: seat, rule });
+                    }
+                }
+                Ok(vec![])
+            }
+            OutOfCardsRule::Lose => {
+                self.end_game(GameOverReason::OutOfCards { loser: seat, rule });
+                Ok(vec![])
+            }
+        }
+    }
+
+    pub fn process_turn(&mut self, card_ids: Vec<CardId>) -> Result<(), GameError> {
+        self.validate_turn(&card_ids)?;
         self.apply_effects()?;
+        let cards = self.take_cards_from_active_hand(&card_ids)?;
         self.apply_cards(cards)?;
         self.draw_cards_for_next_player()?;
         self.update_game_phase();
+        self.bury_dead_philosophers()?;
+        self.turn_history.push(card_ids);
         Ok(())
     }
 
     fn get_player_data(
         &mut self,
         is_active: bool,
-    ) -> Result<(&mut PlayerHand, &mut RemainingDeck), Box<dyn std::error::Error>> {
+    ) -> Result<(&mut PlayerHand, &mut RemainingDeck), GameError> {
         match (&self.game_phase, is_active) {
             (GamePhase::Player1Turn, true) | (GamePhase::Player2Turn, false) => {
                 Ok((&mut self.player_1_hand, &mut self.player_1_deck))
@@ -122,83 +652,320 @@ impl GameBoard {
             (GamePhase::Player2Turn, true) | (GamePhase::Player1Turn, false) => {
                 Ok((&mut self.player_2_hand, &mut self.player_2_deck))
             }
-            _ => Err("bad game phase".into()),
+            _ => Err(GameError::InvalidPhase),
         }
     }
 
     pub fn active_player_data(
         &mut self,
-    ) -> Result<(&mut PlayerHand, &mut RemainingDeck), Box<dyn std::error::Error>> {
+    ) -> Result<(&mut PlayerHand, &mut RemainingDeck), GameError> {
         self.get_player_data(true)
     }
 
     pub fn inactive_player_data(
         &mut self,
-    ) -> Result<(&mut PlayerHand, &mut RemainingDeck), Box<dyn std::error::Error>> {
+    ) -> Result<(&mut PlayerHand, &mut RemainingDeck), GameError> {
         self.get_player_data(false)
     }
 
-    pub fn apply_cards(&mut self, cards: Vec<Card>) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn validate_turn(&self, card_ids: &[CardId]) -> Result<(), GameError> {
+        let active_seat = self
+            .game_phase
+            .active_seat()
+            .ok_or(GameError::InvalidPhase)?;
+        let max_cards = self.game_config.max_cards_played_per_turn();
+        if card_ids.len() > max_cards.into() {
+            return Err(GameError::TooManyCards {
+                played: card_ids.len(),
+                max: max_cards,
+            });
+        }
+        let active_hand = self.hand(&active_seat);
+        for (i, card_id) in card_ids.iter().enumerate() {
+            if !active_hand.owns_card(card_id) {
+                return Err(GameError::NotOwned(*card_id));
+            }
+            if card_ids[..i].contains(card_id) {
+                return Err(GameError::DuplicateCard(*card_id));
+            }
+        }
+        let plays_philosopher = card_ids
+            .iter()
+            .filter_map(|card_id| active_hand.get_card(card_id))
+            .any(|card| matches!(card.card, Card::Philosopher(_) | Card::InPlayPhilosopher(_)));
+        if active_hand.active_philosopher.is_none() && !plays_philosopher {
+            return Err(GameError::NoActivePhilosopher);
+        }
+        Ok(())
+    }
+
+    pub fn legal_turns(&self) -> Vec<Vec<CardId>> {
+        let Some(active_seat) = self.game_phase.active_seat() else {
+            return vec![];
+        };
+        let hand_ids: Vec<CardId> = self
+            .hand(&active_seat)
+            .inactive_cards
+            .iter()
+            .map(|card| card.id)
+            .collect();
+        let max_cards = usize::from(self.game_config.max_cards_played_per_turn());
+        let mut candidate_turns = vec![vec![]];
+        let mut frontier = vec![vec![]];
+        for _ in 0..max_cards.min(hand_ids.len()) {
+            frontier = frontier
+                .iter()
+                .flat_map(|turn: &Vec<CardId>| {
+                    hand_ids
+                        .iter()
+                        .filter(|card_id| !turn.contains(card_id))
+                        .map(|card_id| {
+                            let mut next_turn = turn.clone();
+                            next_turn.push(*card_id);
+                            next_turn
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            candidate_turns.extend(frontier.iter().cloned());
+        }
+
+        let mut seen_outcomes = std::collections::HashSet::new();
+        candidate_turns
+            .into_iter()
+            .filter(|card_ids| self.validate_turn(card_ids).is_ok())
+            .filter(|card_ids| match self.resolve_cards_outcome(card_ids) {
+                Some(outcome) => seen_outcomes.insert(outcome),
+                None => false,
+            })
+            .collect()
+    }
+
+    // copies the board as the observer sees it, filling in what they can't know at random:
+    // the order of both decks and the opponent's hand, apart from philosophers they've seen in play
+    pub fn determinize<R: Rng>(&self, observer: &Seat, rng: &mut R) -> GameBoard {
+        let mut board = self.clone();
+        board.event_log.clear();
+        let (observer_deck, opponent_hand, opponent_deck) = match observer {
+            Seat::Player1 => (
+                &mut board.player_1_deck,
+                &mut board.player_2_hand,
+                &mut board.player_2_deck,
+            ),
+            Seat::Player2 => (
+                &mut board.player_2_deck,
+                &mut board.player_1_hand,
+                &mut board.player_1_deck,
+            ),
+        };
+        observer_deck.reshuffle(rng.next_u64());
+        let (seen_cards, hidden_cards): (Vec<CardInstance>, Vec<CardInstance>) = opponent_hand
+            .inactive_cards
+            .drain(..)
+            .partition(|card| matches!(card.card, Card::InPlayPhilosopher(_)));
+        let num_hidden_cards = hidden_cards.len();
+        opponent_deck.shuffle_in(hidden_cards);
+        opponent_deck.reshuffle(rng.next_u64());
+        opponent_hand.inactive_cards = seen_cards;
+        opponent_hand.inactive_cards.extend(
+            opponent_deck
+                .draw_new_cards(num_hidden_cards)
+                .expect("drawing from the deck can't fail"),
+        );
+        board
+    }
+
+    // resolves the cards on a copy of the board and summarises the state they leave behind,
+    // ignoring orderings (discard order, effect order) that don't change how the game continues
+    fn resolve_cards_outcome(&self, card_ids: &[CardId]) -> Option<TurnOutcome> {
+        let mut board = self.clone();
+        let cards = board.take_cards_from_active_hand(card_ids).ok()?;
+        board.apply_cards(cards).ok()?;
+        Some(TurnOutcome {
+            player_1: SeatOutcome::of(&board, &Seat::Player1),
+            player_2: SeatOutcome::of(&board, &Seat::Player2),
+        })
+    }
+
+    fn take_cards_from_active_hand(
+        &mut self,
+        card_ids: &[CardId],
+    ) -> Result<Vec<CardInstance>, GameError> {
+        let (active_hand, _) = self.active_player_data()?;
+        card_ids
+            .iter()
+            .map(|card_id| active_hand.take_card(card_id))
+            .collect()
+    }
+
+    pub fn apply_cards(&mut self, cards: Vec<CardInstance>) -> Result<(), GameError> {
         for card in cards {
-            match card {
-                Card::Action(action) => self.take_single_action(&action),
-                Card::Philosopher(p) => self.play_philosopher(Card::Philosopher(p)),
-                Card::InPlayPhilosopher(p) => self.play_philosopher(Card::InPlayPhilosopher(p)),
+            match &card.card {
+                Card::Action(action) => {
+                    self.take_single_action(action)?;
+                    self.discard_card(card)
+                }
+                Card::Philosopher(_) | Card::InPlayPhilosopher(_) => self.play_philosopher(card),
             }?
         }
         Ok(())
     }
 
-    fn play_philosopher(&mut self, philosopher: Card) -> Result<(), Box<dyn std::error::Error>> {
+    fn play_philosopher(&mut self, philosopher: CardInstance) -> Result<(), GameError> {
+        let seat = self
+            .game_phase
+            .active_seat()
+            .ok_or(GameError::InvalidPhase)?;
         let (active_player_hand, _active_player_deck) = self.active_player_data()?;
+        let previous = active_player_hand
+            .active_philosopher
+            .as_ref()
+            .map(|p| p.philosopher.name.clone());
         active_player_hand.play_philosopher(philosopher)?;
+        let philosopher = active_player_hand
+            .active_philosopher
+            .as_ref()
+            .map(|p| p.philosopher.name.clone())
+            .ok_or(GameError::NotAPhilosopher)?;
+        self.log_event(GameEvent::PhilosopherSwapped {
+            seat,
+            previous,
+            philosopher,
+        });
         Ok(())
     }
 
     fn get_target(&mut self, ability_type: &AbilityType) -> Option<&mut InPlayPhilosopher> {
         match ability_type {
-            AbilityType::Damage { .. } => match self.game_phase {
-                GamePhase::Player1Turn => self.player_2_hand.active_philosopher.as_mut(),
-                GamePhase::Player2Turn => self.player_1_hand.active_philosopher.as_mut(),
-                _ => None,
-            },
-            AbilityType::Heal { .. } => match self.game_phase {
-                GamePhase::Player1Turn => self.player_1_hand.active_philosopher.as_mut(),
-                GamePhase::Player2Turn => self.player_2_hand.active_philosopher.as_mut(),
-                _ => None,
-            },
+            AbilityType::Damage { .. } => self.get_effect_target(&EffectTarget::Opponent),
+            AbilityType::Heal { .. } => self.get_effect_target(&EffectTarget::Own),
         }
     }
 
-    fn take_single_action(&mut self, card: &Action) -> Result<(), Box<dyn std::error::Error>> {
-        let target = self.get_target(&card.ability_type);
-        match card.ability_type {
-            AbilityType::Heal { heal, duration } => match target {
-                Some(phil) => {
-                    phil.apply_direct_heal(heal);
-                    if duration > 0 {
-                        phil.add_effect(Effect::Recovery {
-                            heal,
+    fn get_effect_target(&mut self, target: &EffectTarget) -> Option<&mut InPlayPhilosopher> {
+        match (&self.game_phase, target) {
+            (GamePhase::Player1Turn, EffectTarget::Own)
+            | (GamePhase::Player2Turn, EffectTarget::Opponent) => {
+                self.player_1_hand.active_philosopher.as_mut()
+            }
+            (GamePhase::Player2Turn, EffectTarget::Own)
+            | (GamePhase::Player1Turn, EffectTarget::Opponent) => {
+                self.player_2_hand.active_philosopher.as_mut()
+            }
+            _ => None,
+        }
+    }
+
+    fn take_single_action(&mut self, card: &Action) -> Result<(), GameError> {
+        self.apply_ability(card);
+        self.apply_additional_effects(card);
+        Ok(())
+    }
+
+    fn apply_additional_effects(&mut self, card: &Action) {
+        for targeted_effect in card.additional_effects() {
+            if let Some(target) = self.get_effect_target(&targeted_effect.target) {
+                target.add_effect(targeted_effect.effect.clone(), card.name());
+            }
+        }
+    }
+
+    fn apply_ability(&mut self, card: &Action) {
+        let Some(active_seat) = self.game_phase.active_seat() else {
+            return;
+        };
+        let Some(target) = self.get_target(&card.ability_type) else {
+            return;
+        };
+        let philosopher = target.philosopher.name.clone();
+        let target_school = target.philosopher.school;
+        let was_dead = target.is_dead();
+        let matchup = self.school_matchups.outcome(card.school(), &target_school);
+        let (base_amount, duration) = match card.ability_type {
+            AbilityType::Damage { damage, duration } => (damage, duration),
+            AbilityType::Heal { heal, duration } => (heal, duration),
+        };
+        let amount = self.school_matchups.scale(base_amount, matchup);
+        let source = card.name().to_string();
+
+        let target = self
+            .get_target(&card.ability_type)
+            .expect("target disappeared while resolving action");
+        let (target_seat, event) = match card.ability_type {
+            AbilityType::Heal { .. } => {
+                target.apply_direct_heal(amount);
+                if duration > 0 {
+                    target.add_effect(
+                        Effect::Recovery {
+                            heal: amount,
                             duration: duration - 1,
-                        });
-                    }
-                    Ok(())
+                        },
+                        &source,
+                    );
                 }
-                None => Ok(()),
-            },
-            AbilityType::Damage { damage, duration } => match target {
-                Some(phil) => {
-                    phil.apply_direct_damage(damage);
-                    if duration > 0 {
-                        phil.add_effect(Effect::Poison {
-                            damage,
+                (
+                    active_seat,
+                    GameEvent::HealApplied {
+                        target: active_seat,
+                        philosopher,
+                        source,
+                        base_amount,
+                        amount,
+                        matchup,
+                    },
+                )
+            }
+            AbilityType::Damage { .. } => {
+                target.apply_direct_damage(amount);
+                if duration > 0 {
+                    target.add_effect(
+                        Effect::Poison {
+                            damage: amount,
                             duration: duration - 1,
-                        });
-                    }
-                    Ok(())
+                        },
+                        &source,
+                    );
                 }
-                None => Ok(()),
-            },
+                (
+                    active_seat.other(),
+                    GameEvent::DamageDealt {
+                        target: active_seat.other(),
+                        philosopher,
+                        source,
+                        base_amount,
+                        amount,
+                        matchup,
+                    },
+                )
+            }
+        };
+        self.log_event(event);
+        self.log_death(target_seat, was_dead);
+    }
+}
+
+#[cfg(test)]
+impl GameBoard {
+    pub fn from_hands(player_1_hand: PlayerHand, player_2_hand: PlayerHand) -> Self {
+        GameBoard {
+            player_1_hand,
+            player_1_deck: RemainingDeck::new(vec![], None),
+            player_1_discard_pile: DiscardPile::default(),
+            player_1_graveyard: Graveyard::default(),
+            player_2_hand,
+            player_2_deck: RemainingDeck::new(vec![], None),
+            player_2_discard_pile: DiscardPile::default(),
+            player_2_graveyard: Graveyard::default(),
+            player_1_fatigue: 0,
+            player_2_fatigue: 0,
+            game_phase: GamePhase::Player1Turn,
+            game_over_reason: None,
+            game_config: GameConfig::default(),
+            school_matchups: Rc::new(SchoolMatchups::default()),
+            event_log: EventLog::default(),
+            turn_history: vec![],
+            player_1_starting_philosopher: None,
+            player_2_starting_philosopher: None,
         }
     }
 }
@@ -206,20 +973,29 @@ impl GameBoard {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{entities::CoreSchool, test_utils};
+    use crate::entities::{school_matchups::Matchup, CoreSchool, Philosopher, TargetedEffect};
+    use crate::game_management::decklist::DecklistEntry;
+    use crate::test_utils::{self, get_example_board};
+    use rand::SeedableRng;
     use uuid::Uuid;
 
-    fn get_example_board() -> GameBoard {
-        let p1_hand = test_utils::get_populated_player_hand(10);
-        let p2_hand = test_utils::get_populated_player_hand(12);
-        let game_board = GameBoard {
-            player_1_hand: p1_hand,
-            player_1_deck: RemainingDeck::new(vec![], None),
-            player_2_hand: p2_hand,
-            player_2_deck: RemainingDeck::new(vec![], None),
-            game_phase: GamePhase::Player1Turn,
-            game_config: GameConfig::default(),
-        };
+    fn get_example_matchup_board() -> GameBoard {
+        let mut game_board = get_example_board();
+        // both example philosophers are Skeptics, example damage is Skeptic, example heal is Rationalist
+        game_board.school_matchups = Rc::new(SchoolMatchups {
+            advantage_percent: 200,
+            disadvantage_percent: 50,
+            matchups: vec![
+                Matchup {
+                    school: CoreSchool::Rationalist,
+                    beats: CoreSchool::Skeptic,
+                },
+                Matchup {
+                    school: CoreSchool::Empiricist,
+                    beats: CoreSchool::Skeptic,
+                },
+            ],
+        });
         game_board
     }
 
@@ -279,7 +1055,7 @@ mod tests {
             target_initial_health - expected_damage
         );
         assert_eq!(post_action_target.effects.len(), 1);
-        let applied_effect = &post_action_target.effects[0];
+        let applied_effect = &post_action_target.effects[0].effect;
         if let Effect::Poison {
             damage: ab_damage,
             duration: ab_duration,
@@ -329,7 +1105,7 @@ mod tests {
             .expect("post-action philosopher not found");
         assert_eq!(post_action_target.remaining_health(), target_initial_health);
         assert_eq!(post_action_target.effects.len(), 1);
-        let applied_effect = &post_action_target.effects[0];
+        let applied_effect = &post_action_target.effects[0].effect;
         if let Effect::Recovery {
             heal: ab_heal,
             duration: ab_duration,
@@ -355,12 +1131,18 @@ mod tests {
             .expect("target philosopher not found");
         let target_initial_health = target.remaining_health();
         let cards = vec![
-            Card::Action(action_card),
-            Card::Philosopher(Philosopher::new(
-                expected_philosopher_name.clone(),
-                CoreSchool::Skeptic,
-                13,
-            )),
+            CardInstance {
+                id: CardId(50),
+                card: Card::Action(action_card),
+            },
+            CardInstance {
+                id: CardId(51),
+                card: Card::Philosopher(Philosopher::new(
+                    expected_philosopher_name.clone(),
+                    CoreSchool::Skeptic,
+                    13,
+                )),
+            },
         ];
         let _ = game_board.apply_cards(cards);
         let post_action_target = game_board
@@ -382,9 +1164,13 @@ mod tests {
     fn test_apply_cards_in_play_philosopher() {
         let mut game_board = get_example_board();
         let expected_name = Uuid::new_v4().to_string();
-        let cards = vec![Card::InPlayPhilosopher(
-            test_utils::get_example_in_play_philosopher(expected_name.clone(), 3),
-        )];
+        let cards = vec![CardInstance {
+            id: CardId(50),
+            card: Card::InPlayPhilosopher(test_utils::get_example_in_play_philosopher(
+                expected_name.clone(),
+                3,
+            )),
+        }];
         let _ = game_board.apply_cards(cards);
         let (active_player_hand, _d) = game_board.active_player_data().unwrap();
         let active_philosopher = active_player_hand.active_philosopher.as_ref().unwrap();
@@ -399,4 +1185,802 @@ mod tests {
         let _ = game_board.apply_cards(cards);
         assert_eq!(format!("{:?}", game_board), game_board_repr)
     }
+
+    #[test]
+    fn test_take_single_action_neutral_matchup() {
+        let mut game_board = get_example_matchup_board();
+        let action_card = unwrap_action_card(test_utils::get_example_damage_action(4, 2));
+        let target_initial_health = game_board
+            .get_target(&action_card.ability_type)
+            .expect("target philosopher not found")
+            .remaining_health();
+        let _ = game_board.take_single_action(&action_card);
+        let post_action_target = game_board
+            .get_target(&action_card.ability_type)
+            .expect("post-action philosopher not found");
+        assert_eq!(
+            post_action_target.remaining_health(),
+            target_initial_health - 4
+        );
+        assert_eq!(post_action_target.effects[0].effect.magnitude(), 4);
+        let Some(GameEvent::DamageDealt { matchup, .. }) =
+            game_board.event_log().last().map(|entry| &entry.event)
+        else {
+            panic!("expected a damage event");
+        };
+        assert_eq!(matchup, &MatchupOutcome::Neutral);
+        assert!(!game_board
+            .event_log()
+            .last()
+            .unwrap()
+            .event
+            .to_string()
+            .contains("against"));
+    }
+
+    #[test]
+    fn test_take_single_action_boosted_heal() {
+        let mut game_board = get_example_matchup_board();
+        let action_card = unwrap_action_card(test_utils::get_example_heal_action(2, 2));
+        let target = game_board.get_target(&action_card.ability_type).unwrap();
+        let target_initial_health = target.remaining_health();
+        target.apply_direct_damage(6);
+        let _ = game_board.take_single_action(&action_card);
+        let post_action_target = game_board
+            .get_target(&action_card.ability_type)
+            .expect("post-action philosopher not found");
+        assert_eq!(
+            post_action_target.remaining_health(),
+            target_initial_health - 2
+        );
+        assert_eq!(post_action_target.effects[0].effect.magnitude(), 4);
+        let log_entry = game_board
+            .event_log()
+            .last()
+            .expect("no event logged")
+            .event
+            .to_string();
+        assert!(log_entry.contains("strong against"));
+        assert!(log_entry.contains("boosted from 2 to 4"));
+    }
+
+    #[test]
+    fn test_take_single_action_weakened_damage() {
+        let expected_damage = 6;
+        let mut game_board = get_example_matchup_board();
+        let action_card = Action::new(
+            "test_weak".into(),
+            "weak_desc".into(),
+            CoreSchool::Skeptic,
+            AbilityType::Damage {
+                damage: expected_damage,
+                duration: 0,
+            },
+            None,
+        );
+        let target = game_board.get_target(&action_card.ability_type).unwrap();
+        target.philosopher.school = CoreSchool::Empiricist;
+        let target_initial_health = target.remaining_health();
+        let _ = game_board.take_single_action(&action_card);
+        let post_action_health = game_board
+            .get_target(&action_card.ability_type)
+            .expect("post-action philosopher not found")
+            .remaining_health();
+        assert_eq!(
+            post_action_health,
+            target_initial_health - expected_damage / 2
+        );
+        let log_entry = game_board
+            .event_log()
+            .last()
+            .expect("no event logged")
+            .event
+            .to_string();
+        assert!(log_entry.contains("weak against"));
+        assert!(log_entry.contains("weakened from 6 to 3"));
+    }
+
+    fn get_action_with_additional_effects(effects: Vec<TargetedEffect>) -> Action {
+        Action::new(
+            "test_additional".into(),
+            "additional_desc".into(),
+            CoreSchool::Skeptic,
+            AbilityType::Damage {
+                damage: 1,
+                duration: 0,
+            },
+            Some(effects),
+        )
+    }
+
+    #[test]
+    fn test_take_single_action_additional_effects_targets() {
+        let mut game_board = get_example_board();
+        let action_card = get_action_with_additional_effects(vec![
+            TargetedEffect {
+                effect: Effect::Poison {
+                    damage: 2,
+                    duration: 3,
+                },
+                target: EffectTarget::Opponent,
+            },
+            TargetedEffect {
+                effect: Effect::Recovery {
+                    heal: 1,
+                    duration: 2,
+                },
+                target: EffectTarget::Own,
+            },
+        ]);
+        let _ = game_board.take_single_action(&action_card);
+        let opponent = game_board
+            .get_effect_target(&EffectTarget::Opponent)
+            .expect("opponent philosopher not found");
+        assert_eq!(opponent.effects.len(), 1);
+        assert!(matches!(
+            opponent.effects[0].effect,
+            Effect::Poison {
+                damage: 2,
+                duration: 3
+            }
+        ));
+        let own = game_board
+            .get_effect_target(&EffectTarget::Own)
+            .expect("own philosopher not found");
+        assert_eq!(own.effects.len(), 1);
+        assert!(matches!(
+            own.effects[0].effect,
+            Effect::Recovery {
+                heal: 1,
+                duration: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn test_take_single_action_additional_effects_follow_active_player() {
+        let mut game_board = get_example_board();
+        game_board.update_game_phase();
+        let action_card = get_action_with_additional_effects(vec![TargetedEffect {
+            effect: Effect::Recovery {
+                heal: 2,
+                duration: 1,
+            },
+            target: EffectTarget::Own,
+        }]);
+        let _ = game_board.take_single_action(&action_card);
+        assert_eq!(
+            game_board
+                .player_2_hand
+                .active_philosopher
+                .as_ref()
+                .unwrap()
+                .effects
+                .len(),
+            1
+        );
+        assert!(game_board
+            .player_1_hand
+            .active_philosopher
+            .as_ref()
+            .unwrap()
+            .effects
+            .is_empty());
+    }
+
+    #[test]
+    fn test_take_single_action_additional_effects_without_main_target() {
+        let mut game_board = get_example_board();
+        game_board.player_2_hand.active_philosopher = None;
+        let action_card = get_action_with_additional_effects(vec![TargetedEffect {
+            effect: Effect::Recovery {
+                heal: 2,
+                duration: 1,
+            },
+            target: EffectTarget::Own,
+        }]);
+        let result = game_board.take_single_action(&action_card);
+        assert!(result.is_ok());
+        let own = game_board
+            .get_effect_target(&EffectTarget::Own)
+            .expect("own philosopher not found");
+        assert_eq!(own.effects.len(), 1);
+    }
+
+    #[test]
+    fn test_process_turn_removes_played_cards_from_hand() {
+        let mut game_board = get_example_board();
+        let played_ids = vec![CardId(0), CardId(2)];
+        let result = game_board.process_turn(played_ids.clone());
+        assert!(result.is_ok());
+        assert_eq!(game_board.game_phase, GamePhase::Player2Turn);
+        for card_id in &played_ids {
+            assert!(!game_board.player_1_hand.owns_card(card_id));
+        }
+        assert!(game_board.player_1_hand.owns_card(&CardId(1)));
+        // the previously active philosopher returns to the hand when replaced
+        assert!(game_board.player_1_hand.owns_card(&CardId(3)));
+        assert_eq!(
+            game_board.player_1_hand.active_philosopher_id,
+            Some(CardId(2))
+        );
+    }
+
+    #[test]
+    fn test_process_turn_rejects_cards_not_owned() {
+        let mut game_board = get_example_board();
+        let game_board_repr = format!("{:?}", game_board);
+        let result = game_board.process_turn(vec![CardId(0), CardId(100)]);
+        assert_eq!(result, Err(GameError::NotOwned(CardId(100))));
+        assert_eq!(format!("{:?}", game_board), game_board_repr);
+    }
+
+    #[test]
+    fn test_process_turn_records_turn_history() {
+        let mut game_board = get_example_board();
+        game_board.process_turn(vec![CardId(0)]).unwrap();
+        assert!(game_board.process_turn(vec![CardId(0)]).is_err());
+        game_board.process_turn(vec![CardId(100)]).unwrap();
+        assert_eq!(
+            game_board.turn_history(),
+            [vec![CardId(0)], vec![CardId(100)]]
+        );
+    }
+
+    #[test]
+    fn test_process_turn_rejects_duplicate_cards() {
+        let mut game_board = get_example_board();
+        let game_board_repr = format!("{:?}", game_board);
+        let result = game_board.process_turn(vec![CardId(0), CardId(0)]);
+        assert_eq!(result, Err(GameError::DuplicateCard(CardId(0))));
+        assert_eq!(format!("{:?}", game_board), game_board_repr);
+    }
+
+    #[test]
+    fn test_process_turn_discards_spent_actions() {
+        let mut game_board = get_example_board();
+        let result = game_board.process_turn(vec![CardId(0), CardId(1)]);
+        assert!(result.is_ok());
+        let discarded_ids: Vec<CardId> = game_board
+            .discard_pile(&Seat::Player1)
+            .cards()
+            .iter()
+            .map(|card| card.id)
+            .collect();
+        assert_eq!(discarded_ids, vec![CardId(0), CardId(1)]);
+        assert_eq!(game_board.discard_pile(&Seat::Player2).num_cards(), 0);
+    }
+
+    #[test]
+    fn test_process_turn_buries_dead_philosophers() {
+        let mut game_board = get_example_board();
+        game_board
+            .player_2_hand
+            .active_philosopher
+            .as_mut()
+            .unwrap()
+            .apply_direct_damage(11);
+        let result = game_board.process_turn(vec![CardId(0)]);
+        assert!(result.is_ok());
+        assert_eq!(game_board.game_phase, GamePhase::GameOver);
+        assert!(game_board.player_2_hand.active_philosopher.is_none());
+        let graveyard = game_board.graveyard(&Seat::Player2);
+        assert_eq!(graveyard.num_cards(), 1);
+        assert_eq!(
+            graveyard.philosophers().next().unwrap().philosopher.name,
+            "test"
+        );
+        assert_eq!(game_board.graveyard(&Seat::Player1).num_cards(), 0);
+    }
+
+    fn get_example_board_with_rule(rule: OutOfCardsRule) -> GameBoard {
+        let mut game_board = get_example_board();
+        game_board.game_config = GameConfig {
+            out_of_cards_rule: rule,
+            ..GameConfig::default()
+        };
+        game_board
+    }
+
+    #[test]
+    fn test_piles_count_past_255_cards() {
+        let mut game_board = get_example_board_with_rule(OutOfCardsRule::Reshuffle);
+        let first_ids = (0..100).map(|i| 1000 + 3 * i);
+        for card in first_ids.flat_map(test_utils::get_example_cards) {
+            game_board.player_2_discard_pile.add_card(card);
+        }
+        assert_eq!(game_board.discard_pile(&Seat::Player2).num_cards(), 300);
+        game_board.process_turn(vec![CardId(0)]).unwrap();
+        assert_eq!(game_board.discard_pile(&Seat::Player2).num_cards(), 0);
+        assert_eq!(
+            game_board
+                .remaining_deck(&Seat::Player2)
+                .num_remaining_cards(),
+            298
+        );
+    }
+
+    #[test]
+    fn test_out_of_cards_reshuffle() {
+        let mut game_board = get_example_board_with_rule(OutOfCardsRule::Reshuffle);
+        for card in test_utils::get_example_cards(200) {
+            game_board.player_2_discard_pile.add_card(card);
+        }
+        let p2_initial_cards = game_board.player_2_hand.inactive_cards.len();
+        let result = game_board.process_turn(vec![CardId(0)]);
+        assert!(result.is_ok());
+        assert_eq!(game_board.game_phase, GamePhase::Player2Turn);
+        assert_eq!(
+            game_board.player_2_hand.inactive_cards.len(),
+            p2_initial_cards + 2
+        );
+        assert_eq!(game_board.discard_pile(&Seat::Player2).num_cards(), 0);
+        assert_eq!(
+            game_board
+                .remaining_deck(&Seat::Player2)
+                .num_remaining_cards(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_out_of_cards_reshuffle_with_empty_discard_pile() {
+        let mut game_board = get_example_board_with_rule(OutOfCardsRule::Reshuffle);
+        let p2_initial_cards = game_board.player_2_hand.inactive_cards.len();
+        let result = game_board.process_turn(vec![CardId(0)]);
+        assert!(result.is_ok());
+        assert_eq!(game_board.game_phase, GamePhase::Player2Turn);
+        assert_eq!(
+            game_board.player_2_hand.inactive_cards.len(),
+            p2_initial_cards
+        );
+    }
+
+    #[test]
+    fn test_out_of_cards_fatigue_escalates() {
+        let mut game_board = get_example_board_with_rule(OutOfCardsRule::Fatigue);
+        let p2_health = |board: &GameBoard| {
+            board
+                .player_2_hand
+                .active_philosopher
+                .as_ref()
+                .unwrap()
+                .remaining_health()
+        };
+        let initial_health = p2_health(&game_board);
+        game_board.draw_cards_for_next_player().unwrap();
+        assert_eq!(p2_health(&game_board), initial_health - 1);
+        game_board.draw_cards_for_next_player().unwrap();
+        assert_eq!(p2_health(&game_board), initial_health - 3);
+        assert_eq!(game_board.player_2_fatigue, 2);
+        assert_eq!(game_board.player_1_fatigue, 0);
+    }
+
+    #[test]
+    fn test_out_of_cards_fatigue_ends_game() {
+        let mut game_board = get_example_board_with_rule(OutOfCardsRule::Fatigue);
+        game_board.player_2_fatigue = 20;
+        let result = game_board.process_turn(vec![CardId(1)]);
+        assert!(result.is_ok());
+        assert_eq!(game_board.game_phase, GamePhase::GameOver);
+        let reason = game_board.game_over_reason().expect("no game over reason");
+        assert_eq!(
+            reason,
+            &GameOverReason::OutOfCards {
+                loser: Seat::Player2,
+                rule: OutOfCardsRule::Fatigue
+            }
+        );
+        assert!(reason.to_string().contains("fatigue"));
+    }
+
+    #[test]
+    fn test_out_of_cards_lose() {
+        let mut game_board = get_example_board_with_rule(OutOfCardsRule::Lose);
+        let result = game_board.process_turn(vec![CardId(1)]);
+        assert!(result.is_ok());
+        assert_eq!(game_board.game_phase, GamePhase::GameOver);
+        let reason = game_board.game_over_reason().expect("no game over reason");
+        assert_eq!(
+            reason,
+            &GameOverReason::OutOfCards {
+                loser: Seat::Player2,
+                rule: OutOfCardsRule::Lose
+            }
+        );
+        assert!(reason.to_string().contains("lose"));
+    }
+
+    #[test]
+    fn test_out_of_cards_rule_not_triggered_with_full_hand() {
+        let mut game_board = get_example_board_with_rule(OutOfCardsRule::Lose);
+        game_board.player_2_hand.max_cards_in_hand = 3;
+        let result = game_board.process_turn(vec![CardId(1)]);
+        assert!(result.is_ok());
+        assert_eq!(game_board.game_phase, GamePhase::Player2Turn);
+        assert!(game_board.game_over_reason().is_none());
+    }
+
+    #[test]
+    fn test_validate_turn_ok() {
+        let game_board = get_example_board();
+        assert_eq!(game_board.validate_turn(&[]), Ok(()));
+        assert_eq!(
+            game_board.validate_turn(&[CardId(0), CardId(1), CardId(2)]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_turn_too_many_cards() {
+        let mut game_board = get_example_board();
+        let _ = game_board
+            .player_1_hand
+            .add_cards_to_hand(test_utils::get_example_cards(10));
+        let result = game_board.validate_turn(&[CardId(0), CardId(1), CardId(2), CardId(10)]);
+        assert_eq!(
+            result,
+            Err(GameError::TooManyCards {
+                played: 4,
+                max: game_board.game_config.max_cards_played_per_turn()
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_turn_no_active_philosopher() {
+        let mut game_board = get_example_board();
+        game_board.player_1_hand.active_philosopher = None;
+        game_board.player_1_hand.active_philosopher_id = None;
+        assert_eq!(
+            game_board.validate_turn(&[CardId(0), CardId(1)]),
+            Err(GameError::NoActivePhilosopher)
+        );
+        assert_eq!(game_board.validate_turn(&[CardId(0), CardId(2)]), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_turn_not_owned() {
+        let game_board = get_example_board();
+        assert_eq!(
+            game_board.validate_turn(&[CardId(101)]),
+            Err(GameError::NotOwned(CardId(101)))
+        );
+    }
+
+    #[test]
+    fn test_validate_turn_game_over() {
+        let mut game_board = get_example_board();
+        game_board.game_phase = GamePhase::GameOver;
+        assert_eq!(
+            game_board.validate_turn(&[CardId(0)]),
+            Err(GameError::InvalidPhase)
+        );
+    }
+
+    #[test]
+    fn test_process_turn_rejects_invalid_turn_without_changes() {
+        let mut game_board = get_example_board();
+        game_board.player_1_hand.active_philosopher = None;
+        game_board.player_1_hand.active_philosopher_id = None;
+        let game_board_repr = format!("{:?}", game_board);
+        let result = game_board.process_turn(vec![CardId(0)]);
+        assert_eq!(result, Err(GameError::NoActivePhilosopher));
+        assert_eq!(format!("{:?}", game_board), game_board_repr);
+    }
+
+    #[test]
+    fn test_legal_turns_are_valid() {
+        let game_board = get_example_board();
+        let legal_turns = game_board.legal_turns();
+        assert!(!legal_turns.is_empty());
+        for turn in &legal_turns {
+            assert_eq!(game_board.validate_turn(turn), Ok(()));
+        }
+        assert!(legal_turns.contains(&vec![]));
+    }
+
+    #[test]
+    fn test_legal_turns_respect_card_limit() {
+        let mut game_board = get_example_board();
+        game_board.game_config = GameConfig {
+            num_cards_played_per_turn: 1,
+            ..GameConfig::default()
+        };
+        let legal_turns = game_board.legal_turns();
+        assert_eq!(
+            legal_turns,
+            vec![vec![], vec![CardId(0)], vec![CardId(1)], vec![CardId(2)]]
+        );
+    }
+
+    #[test]
+    fn test_legal_turns_require_philosopher() {
+        let mut game_board = get_example_board();
+        game_board.player_1_hand.active_philosopher = None;
+        game_board.player_1_hand.active_philosopher_id = None;
+        let legal_turns = game_board.legal_turns();
+        assert!(!legal_turns.is_empty());
+        for turn in &legal_turns {
+            assert!(turn.contains(&CardId(2)));
+        }
+    }
+
+    #[test]
+    fn test_legal_turns_keep_orderings_that_matter() {
+        let game_board = get_example_board();
+        let legal_turns = game_board.legal_turns();
+        // healing before or after swapping philosophers heals different philosophers
+        assert!(legal_turns.contains(&vec![CardId(1), CardId(2)]));
+        assert!(legal_turns.contains(&vec![CardId(2), CardId(1)]));
+    }
+
+    #[test]
+    fn test_legal_turns_remove_orderings_that_dont_matter() {
+        let game_board = get_example_board();
+        let legal_turns = game_board.legal_turns();
+        // damaging the opponent and healing yourself can happen in either order
+        let damage_then_heal = legal_turns.contains(&vec![CardId(0), CardId(1)]);
+        let heal_then_damage = legal_turns.contains(&vec![CardId(1), CardId(0)]);
+        assert!(damage_then_heal ^ heal_then_damage);
+        let damage_then_swap = legal_turns.contains(&vec![CardId(0), CardId(2)]);
+        let swap_then_damage = legal_turns.contains(&vec![CardId(2), CardId(0)]);
+        assert!(damage_then_swap ^ swap_then_damage);
+    }
+
+    #[test]
+    fn test_legal_turns_keep_orderings_that_change_a_benched_philosopher() {
+        let mut game_board = get_example_board();
+        game_board.player_1_hand.inactive_cards[1].card = test_utils::get_example_heal_action(3, 0);
+        game_board
+            .player_1_hand
+            .active_philosopher
+            .as_mut()
+            .unwrap()
+            .apply_direct_damage(3);
+        let legal_turns = game_board.legal_turns();
+        // healing before the swap benches a philosopher at 10 health, swapping first leaves it at 7
+        assert!(legal_turns.contains(&vec![CardId(1), CardId(2)]));
+        assert!(legal_turns.contains(&vec![CardId(2), CardId(1)]));
+    }
+
+    #[test]
+    fn test_determinize_hides_only_unknown_cards() {
+        let mut game_board = get_example_board();
+        game_board.player_2_deck = RemainingDeck::new(test_utils::get_example_cards(200), None);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let mut determinized = game_board.determinize(&Seat::Player1, &mut rng);
+        assert_eq!(
+            format!("{:?}", determinized.player_1_hand),
+            format!("{:?}", game_board.player_1_hand)
+        );
+        assert_eq!(determinized.player_2_hand.inactive_cards.len(), 3);
+        let mut player_2_ids: Vec<CardId> = determinized
+            .player_2_deck
+            .draw_new_cards(64)
+            .unwrap()
+            .iter()
+            .chain(determinized.player_2_hand.inactive_cards.iter())
+            .map(|card| card.id)
+            .collect();
+        player_2_ids.sort();
+        assert_eq!(
+            player_2_ids,
+            [100, 101, 102, 200, 201, 202].map(CardId).to_vec()
+        );
+    }
+
+    #[test]
+    fn test_new_with_same_seed_replays_exactly() {
+        let game_config = GameConfig::default().with_seed(1234);
+        let mut game_board_1 = GameBoard::new(Some(game_config.clone())).unwrap();
+        let mut game_board_2 = GameBoard::new(Some(game_config)).unwrap();
+        assert_eq!(format!("{:?}", game_board_1), format!("{:?}", game_board_2));
+        for _ in 0..10 {
+            let Some(turn) = game_board_1.legal_turns().pop() else {
+                break;
+            };
+            game_board_1.process_turn(turn.clone()).unwrap();
+            game_board_2.process_turn(turn).unwrap();
+            assert_eq!(format!("{:?}", game_board_1), format!("{:?}", game_board_2));
+        }
+    }
+
+    #[test]
+    fn test_new_records_dealt_starting_philosophers() {
+        let mut game_board = GameBoard::new(Some(GameConfig::default().with_seed(3))).unwrap();
+        let dealt = game_board.player_1_hand.inactive_cards[0]
+            .card
+            .name()
+            .to_string();
+        assert_eq!(
+            game_board.starting_philosopher(&Seat::Player1),
+            Some(dealt.as_str())
+        );
+        let turn = game_board.legal_turns().pop().unwrap();
+        game_board.process_turn(turn).unwrap();
+        assert_eq!(
+            game_board.starting_philosopher(&Seat::Player1),
+            Some(dealt.as_str())
+        );
+        assert!(game_board.starting_philosopher(&Seat::Player2).is_some());
+    }
+
+    #[test]
+    fn test_every_config_preset_starts_a_game() {
+        for preset in [
+            ConfigPreset::Standard,
+            ConfigPreset::Quick,
+            ConfigPreset::Endurance,
+        ] {
+            let game_config = GameConfig::from_preset(preset);
+            let max_cards_in_hand = game_config.max_cards_in_hand();
+            let game_board = GameBoard::new(Some(game_config)).unwrap();
+            assert!(game_board.player_1_hand.inactive_cards.len() <= max_cards_in_hand.into());
+        }
+        assert_eq!(
+            GameConfig::from_preset(ConfigPreset::Quick).out_of_cards_rule(),
+            OutOfCardsRule::Lose
+        );
+    }
+
+    #[test]
+    fn test_each_seat_deals_from_its_own_decklist() {
+        let decklist = |philosopher: &str, action: &str| Decklist {
+            name: format!("{} deck", philosopher),
+            cards: [
+                (philosopher, 3),
+                (action, 3),
+                ("Abstract thought", 3),
+                ("Radical Doubt", 1),
+            ]
+            .map(|(card, count)| DecklistEntry {
+                card: card.into(),
+                count,
+            })
+            .to_vec(),
+        };
+        let game_config = GameConfig::default()
+            .with_decklist(
+                Seat::Player1,
+                Some(decklist("Plato", "Logical Argumentation")),
+            )
+            .with_decklist(
+                Seat::Player2,
+                Some(decklist("Pyrrho", "Suspension of Belief")),
+            );
+        let game_board = GameBoard::new(Some(game_config)).unwrap();
+        for (seat, philosopher) in [(Seat::Player1, "Plato"), (Seat::Player2, "Pyrrho")] {
+            let hand = game_board.hand(&seat);
+            let Card::Philosopher(starting_philosopher) = &hand.inactive_cards[0].card else {
+                panic!("expected the starting philosopher first");
+            };
+            assert_eq!(starting_philosopher.name, philosopher);
+            let num_cards =
+                hand.inactive_cards.len() + game_board.remaining_deck(&seat).num_remaining_cards();
+            assert_eq!(num_cards, 10);
+        }
+    }
+
+    #[test]
+    fn test_deck_rules_come_from_config() {
+        let decklist = Decklist {
+            name: "Tiny".into(),
+            cards: [("Plato", 5), ("Radical Doubt", 1)]
+                .map(|(card, count)| DecklistEntry {
+                    card: card.into(),
+                    count,
+                })
+                .to_vec(),
+        };
+        let deck_rules = DeckRules {
+            min_deck_size: 6,
+            max_deck_size: 6,
+            max_copies: 5,
+            min_philosophers: 1,
+        };
+        let game_config = GameConfig::default()
+            .with_deck_rules(deck_rules.clone())
+            .with_decklist(Seat::Player1, Some(decklist));
+        assert_eq!(game_config.deck_rules(), &deck_rules);
+        assert!(GameBoard::new(Some(game_config)).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_decklist_is_rejected() {
+        let decklist = Decklist {
+            name: "Tiny".into(),
+            cards: vec![DecklistEntry {
+                card: "Plato".into(),
+                count: 2,
+            }],
+        };
+        let game_config = GameConfig::default().with_decklist(Seat::Player2, Some(decklist));
+        assert!(matches!(
+            GameBoard::new(Some(game_config)),
+            Err(GameError::InvalidDeck { deck, .. }) if deck == "Tiny"
+        ));
+    }
+
+    #[test]
+    fn test_process_turn_logs_events() {
+        let mut game_board = get_example_board();
+        game_board.process_turn(vec![CardId(2), CardId(0)]).unwrap();
+        let events: Vec<&GameEvent> = game_board
+            .event_log()
+            .for_turn(0)
+            .map(|entry| &entry.event)
+            .collect();
+        assert_eq!(
+            events,
+            [
+                &GameEvent::PhilosopherSwapped {
+                    seat: Seat::Player1,
+                    previous: Some("test".into()),
+                    philosopher: "hand_philos".into(),
+                },
+                &GameEvent::DamageDealt {
+                    target: Seat::Player2,
+                    philosopher: "test".into(),
+                    source: "test_dam".into(),
+                    base_amount: 1,
+                    amount: 1,
+                    matchup: MatchupOutcome::Neutral,
+                },
+                &GameEvent::PhaseChanged {
+                    phase: GamePhase::Player2Turn,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_turn_logs_effect_ticks_and_deaths() {
+        let mut game_board = get_example_board();
+        let opponent = game_board
+            .player_2_hand
+            .active_philosopher
+            .as_mut()
+            .unwrap();
+        opponent.apply_direct_damage(9);
+        opponent.add_effect(
+            Effect::Poison {
+                damage: 5,
+                duration: 2,
+            },
+            "Radical Doubt",
+        );
+        game_board.process_turn(vec![CardId(1)]).unwrap();
+        let events: Vec<&GameEvent> = game_board
+            .event_log()
+            .entries()
+            .iter()
+            .map(|entry| &entry.event)
+            .collect();
+        assert_eq!(
+            events[0],
+            &GameEvent::EffectTicked {
+                target: Seat::Player2,
+                philosopher: "test".into(),
+                source: "Radical Doubt".into(),
+                effect: Effect::Poison {
+                    damage: 5,
+                    duration: 1
+                },
+                amount: 3,
+            }
+        );
+        assert_eq!(
+            events[1],
+            &GameEvent::PhilosopherDied {
+                seat: Seat::Player2,
+                philosopher: "test".into(),
+            }
+        );
+        assert!(events.contains(&&GameEvent::GameOver {
+            reason: GameOverReason::PhilosopherDefeated {
+                loser: Seat::Player2
+            }
+        }));
+    }
 }
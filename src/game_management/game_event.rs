@@ -0,0 +1,279 @@
+use crate::entities::{Effect, MatchupOutcome};
+use crate::game_management::{GameOverReason, GamePhase, Seat};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum GameEvent {
+    EffectTicked {
+        target: Seat,
+        philosopher: String,
+        source: String,
+        // the effect as it is after ticking, so its duration is the number of turns left
+        effect: Effect,
+        amount: u8,
+    },
+    DamageDealt {
+        target: Seat,
+        philosopher: String,
+        source: String,
+        base_amount: u8,
+        amount: u8,
+        matchup: MatchupOutcome,
+    },
+    HealApplied {
+        target: Seat,
+        philosopher: String,
+        source: String,
+        base_amount: u8,
+        amount: u8,
+        matchup: MatchupOutcome,
+    },
+    PhilosopherSwapped {
+        seat: Seat,
+        previous: Option<String>,
+        philosopher: String,
+    },
+    PhilosopherDied {
+        seat: Seat,
+        philosopher: String,
+    },
+    CardsDrawn {
+        seat: Seat,
+        count: usize,
+    },
+    PhaseChanged {
+        phase: GamePhase,
+    },
+    GameOver {
+        reason: GameOverReason,
+    },
+}
+impl GameEvent {
+    /// The seat the event happened to, if it is about a single player.
+    pub fn seat(&self) -> Option<Seat> {
+        match self {
+            GameEvent::EffectTicked { target, .. }
+            | GameEvent::DamageDealt { target, .. }
+            | GameEvent::HealApplied { target, .. } => Some(*target),
+            GameEvent::PhilosopherSwapped { seat, .. }
+            | GameEvent::PhilosopherDied { seat, .. }
+            | GameEvent::CardsDrawn { seat, .. } => Some(*seat),
+            GameEvent::PhaseChanged { phase } => phase.active_seat(),
+            GameEvent::GameOver { .. } => None,
+        }
+    }
+}
+fn matchup_note(
+    source: &str,
+    philosopher: &str,
+    kind: &str,
+    matchup: &MatchupOutcome,
+    base_amount: u8,
+    amount: u8,
+) -> String {
+    let (relation, change) = match matchup {
+        MatchupOutcome::Advantage => ("strong", "boosted"),
+        MatchupOutcome::Disadvantage => ("weak", "weakened"),
+        MatchupOutcome::Neutral => return String::new(),
+    };
+    format!(
+        " ({} is {} against {}: {} {} from {} to {})",
+        source, relation, philosopher, kind, change, base_amount, amount
+    )
+}
+
+impl std::fmt::Display for GameEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let turns_left = |duration: u8| match duration {
+            1 => "1 turn left".to_string(),
+            duration => format!("{} turns left", duration),
+        };
+        match self {
+            GameEvent::EffectTicked {
+                philosopher,
+                source,
+                effect: effect @ Effect::Poison { .. },
+                amount,
+                ..
+            } => write!(
+                f,
+                "{} dealt {} to {} (poison {})",
+                source,
+                amount,
+                philosopher,
+                turns_left(effect.duration())
+            ),
+            GameEvent::EffectTicked {
+                philosopher,
+                source,
+                effect: effect @ Effect::Recovery { .. },
+                amount,
+                ..
+            } => write!(
+                f,
+                "{} healed {} for {} (recovery {})",
+                source,
+                philosopher,
+                amount,
+                turns_left(effect.duration())
+            ),
+            GameEvent::DamageDealt {
+                philosopher,
+                source,
+                base_amount,
+                amount,
+                matchup,
+                ..
+            } => write!(
+                f,
+                "{} dealt {} to {}{}",
+                source,
+                amount,
+                philosopher,
+                matchup_note(
+                    source,
+                    philosopher,
+                    "damage",
+                    matchup,
+                    *base_amount,
+                    *amount
+                )
+            ),
+            GameEvent::HealApplied {
+                philosopher,
+                source,
+                base_amount,
+                amount,
+                matchup,
+                ..
+            } => write!(
+                f,
+                "{} healed {} for {}{}",
+                source,
+                philosopher,
+                amount,
+                matchup_note(source, philosopher, "heal", matchup, *base_amount, *amount)
+            ),
+            GameEvent::PhilosopherSwapped {
+                seat,
+                previous: Some(previous),
+                philosopher,
+            } => write!(f, "{} swapped {} for {}", seat, previous, philosopher),
+            GameEvent::PhilosopherSwapped {
+                seat,
+                previous: None,
+                philosopher,
+            } => write!(f, "{} brought {} into play", seat, philosopher),
+            GameEvent::PhilosopherDied { seat, philosopher } => {
+                write!(f, "{}'s {} has been defeated", seat, philosopher)
+            }
+            GameEvent::CardsDrawn { seat, count } => {
+                write!(f, "{} drew {} card(s)", seat, count)
+            }
+            GameEvent::PhaseChanged { phase } => write!(f, "Now {}", phase),
+            GameEvent::GameOver { reason } => write!(f, "Game over: {}", reason),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LoggedEvent {
+    pub turn: usize,
+    pub event: GameEvent,
+}
+
+/// Every event the board has emitted, tagged with the index of the turn that caused it.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EventLog {
+    entries: Vec<LoggedEvent>,
+}
+impl EventLog {
+    pub fn push(&mut self, turn: usize, event: GameEvent) {
+        self.entries.push(LoggedEvent { turn, event });
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn entries(&self) -> &[LoggedEvent] {
+        &self.entries
+    }
+
+    pub fn last(&self) -> Option<&LoggedEvent> {
+        self.entries.last()
+    }
+
+    pub fn for_turn(&self, turn: usize) -> impl Iterator<Item = &LoggedEvent> {
+        self.entries.iter().filter(move |entry| entry.turn == turn)
+    }
+
+    pub fn involving(&self, seat: Seat) -> impl Iterator<Item = &LoggedEvent> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.event.seat().is_none_or(|s| s == seat))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effect_ticked_display() {
+        let event = GameEvent::EffectTicked {
+            target: Seat::Player2,
+            philosopher: "Plato".into(),
+            source: "Radical Doubt".into(),
+            effect: Effect::Poison {
+                damage: 2,
+                duration: 2,
+            },
+            amount: 2,
+        };
+        assert_eq!(
+            event.to_string(),
+            "Radical Doubt dealt 2 to Plato (poison 2 turns left)"
+        );
+    }
+
+    #[test]
+    fn test_event_log_queries() {
+        let mut event_log = EventLog::default();
+        event_log.push(
+            0,
+            GameEvent::CardsDrawn {
+                seat: Seat::Player2,
+                count: 2,
+            },
+        );
+        event_log.push(
+            1,
+            GameEvent::CardsDrawn {
+                seat: Seat::Player1,
+                count: 1,
+            },
+        );
+        event_log.push(
+            1,
+            GameEvent::GameOver {
+                reason: GameOverReason::PhilosopherDefeated {
+                    loser: Seat::Player2,
+                },
+            },
+        );
+        assert_eq!(
+            event_log
+                .for_turn(1)
+                .map(|logged| logged.event.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "Player 1 drew 1 card(s)",
+                "Game over: Player 2's philosopher was defeated"
+            ]
+        );
+        // events that aren't about one player show up for both
+        assert_eq!(event_log.involving(Seat::Player1).count(), 2);
+        assert_eq!(event_log.involving(Seat::Player2).count(), 2);
+    }
+}
@@ -1,21 +1,124 @@
-use crate::entities::{Action, Card, Philosopher};
+use crate::entities::{Card, CardIdGenerator, SchoolMatchups};
+use crate::game_error::GameError;
+use crate::game_management::card_pack::CardSet;
 use crate::player::{PlayerHand, RemainingDeck};
-use rand::{rng, Rng};
-use serde_yaml;
+use rand::{rngs::StdRng, Rng, RngCore};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub(super) const PHILOSOPHERS_FILE: &str = "philosophers.yaml";
+pub(super) const ACTIONS_FILE: &str = "actions.yaml";
+const SCHOOL_MATCHUPS_FILE: &str = "school_matchups.yaml";
+pub(super) const DECK_RULES_FILE: &str = "deck_rules.yaml";
+const ASSET_DIR_VAR: &str = "INFINITE_DEBATE_ASSETS";
+// cards drawn on top of the starting philosopher
+pub const OPENING_HAND_SIZE: u8 = 4;
+
+// compiled in so the game runs from any working directory
+const EMBEDDED_ASSETS: [(&str, &str); 7] = [
+    (
+        PHILOSOPHERS_FILE,
+        include_str!("../../assets/philosophers.yaml"),
+    ),
+    (ACTIONS_FILE, include_str!("../../assets/actions.yaml")),
+    (
+        SCHOOL_MATCHUPS_FILE,
+        include_str!("../../assets/school_matchups.yaml"),
+    ),
+    (
+        DECK_RULES_FILE,
+        include_str!("../../assets/deck_rules.yaml"),
+    ),
+    (
+        "themes/dark.yaml",
+        include_str!("../../assets/themes/dark.yaml"),
+    ),
+    (
+        "themes/light.yaml",
+        include_str!("../../assets/themes/light.yaml"),
+    ),
+    (
+        "themes/high_contrast.yaml",
+        include_str!("../../assets/themes/high_contrast.yaml"),
+    ),
+];
+
+static ASSET_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Overrides the `INFINITE_DEBATE_ASSETS` environment variable. Only the first call has any effect.
+pub fn set_asset_dir(dir: PathBuf) {
+    let _ = ASSET_DIR.set(dir);
+}
+
+pub(super) fn asset_dir() -> Option<PathBuf> {
+    ASSET_DIR
+        .get()
+        .cloned()
+        .or_else(|| std::env::var_os(ASSET_DIR_VAR).map(PathBuf::from))
+}
+
+/// Where a user file or folder lives: next to the other assets when there is an override
+/// directory, or in the working directory otherwise.
+pub(super) fn user_dir(name: &str) -> PathBuf {
+    asset_dir().unwrap_or_else(|| PathBuf::from(".")).join(name)
+}
+
+/// The entries in `dir` that `keep` accepts, sorted, or none if the folder doesn't exist yet.
+pub(super) fn scan_dir(
+    dir: &Path,
+    keep: impl Fn(&Path) -> bool,
+) -> Result<Vec<PathBuf>, GameError> {
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let entries = std::fs::read_dir(dir).map_err(|e| GameError::AssetLoad {
+        path: dir.to_string_lossy().into(),
+        reason: e.to_string(),
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| keep(path))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+pub(super) fn is_yaml_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "yaml")
+}
 
 pub fn get_intial_deck(
+    mut deck_cards: Vec<Card>,
     max_cards_in_hand: &u8,
-) -> Result<(PlayerHand, RemainingDeck), Box<dyn std::error::Error>> {
-    let mut philosophers = get_philosopher_cards()?;
-    let random_index = rng().random_range(0..philosophers.len());
-    let initial_philosopher = philosophers.remove(random_index);
-    let actions = get_action_cards()?;
-    let mut remaining_deck_cards = philosophers;
-    remaining_deck_cards.extend(actions);
-    let mut remaining_deck = RemainingDeck::new(remaining_deck_cards, None);
-    let player_initial_cards = remaining_deck.draw_new_cards(4);
+    card_ids: &mut CardIdGenerator,
+    seeds: &mut StdRng,
+) -> Result<(PlayerHand, RemainingDeck), GameError> {
+    let philosopher_positions: Vec<usize> = deck_cards
+        .iter()
+        .enumerate()
+        .filter(|(_, card)| matches!(card, Card::Philosopher(_)))
+        .map(|(i, _)| i)
+        .collect();
+    if philosopher_positions.is_empty() {
+        return Err(GameError::AssetLoad {
+            path: PHILOSOPHERS_FILE.into(),
+            reason: "no philosophers defined".into(),
+        });
+    }
+    let random_index = seeds.random_range(0..philosopher_positions.len());
+    let initial_philosopher =
+        card_ids.instantiate(deck_cards.remove(philosopher_positions[random_index]));
+    let remaining_deck_cards = deck_cards
+        .into_iter()
+        .map(|card| card_ids.instantiate(card))
+        .collect();
+    let mut remaining_deck = RemainingDeck::new(remaining_deck_cards, Some(seeds.next_u64()));
+    let player_initial_cards = remaining_deck.draw_new_cards(usize::from(OPENING_HAND_SIZE));
     let mut player_hand = PlayerHand {
         active_philosopher: None,
+        active_philosopher_id: None,
         inactive_cards: vec![initial_philosopher],
         max_cards_in_hand: *max_cards_in_hand,
     };
@@ -23,22 +126,159 @@ pub fn get_intial_deck(
     Ok((player_hand, remaining_deck))
 }
 
-fn get_philosopher_cards() -> Result<Vec<Box<Card>>, Box<dyn std::error::Error>> {
-    let f = std::fs::File::open("./assets/philosophers.yaml")?;
-    let d: Vec<Philosopher> = serde_yaml::from_reader(f)?;
-    let philosopher_cards: Vec<Box<Card>> = d
-        .into_iter()
-        .map(|card| Box::new(Card::Philosopher(card)))
-        .collect();
-    Ok(philosopher_cards)
+fn parse_yaml<T: DeserializeOwned>(path: &str, contents: &str) -> Result<T, GameError> {
+    // serde_yaml's message already ends with the line and column of the problem
+    serde_yaml::from_str(contents).map_err(|e| GameError::AssetLoad {
+        path: path.into(),
+        reason: e.to_string(),
+    })
 }
 
-fn get_action_cards() -> Result<Vec<Box<Card>>, Box<dyn std::error::Error>> {
-    let f = std::fs::File::open("./assets/actions.yaml")?;
-    let d: Vec<Action> = serde_yaml::from_reader(f)?;
-    let action_cards: Vec<Box<Card>> = d
-        .into_iter()
-        .map(|card| Box::new(Card::Action(card)))
-        .collect();
-    Ok(action_cards)
+pub fn load_yaml<T: DeserializeOwned>(path: &str) -> Result<T, GameError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| GameError::AssetLoad {
+        path: path.into(),
+        reason: e.to_string(),
+    })?;
+    parse_yaml(path, &contents)
+}
+
+/// Loads a file from the asset directory override if it has one, or the built-in copy otherwise.
+pub fn load_asset<T: DeserializeOwned>(file_name: &str) -> Result<T, GameError> {
+    load_asset_from(asset_dir().as_deref(), file_name)
+}
+
+fn load_asset_from<T: DeserializeOwned>(
+    asset_dir: Option<&Path>,
+    file_name: &str,
+) -> Result<T, GameError> {
+    let (path, contents) = read_asset(asset_dir, file_name)?;
+    parse_yaml(&path, &contents)
+}
+
+/// Loads a list asset one entry at a time, so a bad entry doesn't hide the rest of the file.
+pub(super) fn load_asset_entries_from<T: DeserializeOwned>(
+    asset_dir: Option<&Path>,
+    file_name: &str,
+) -> Result<Vec<Result<T, GameError>>, GameError> {
+    let (path, contents) = read_asset(asset_dir, file_name)?;
+    let entries: Vec<serde_yaml::Value> = parse_yaml(&path, &contents)?;
+    let parse_entry = |(i, entry): (usize, serde_yaml::Value)| {
+        let entry_name = match entry.get("name").and_then(|name| name.as_str()) {
+            Some(name) => format!("entry {} ('{}')", i + 1, name),
+            None => format!("entry {}", i + 1),
+        };
+        serde_yaml::from_value(entry).map_err(|e| GameError::AssetLoad {
+            path: path.clone(),
+            reason: format!("{}: {}", entry_name, e),
+        })
+    };
+    Ok(entries.into_iter().enumerate().map(parse_entry).collect())
+}
+
+// the path to report errors against and the file contents, from the override or built in
+fn read_asset(asset_dir: Option<&Path>, file_name: &str) -> Result<(String, String), GameError> {
+    if let Some(dir) = asset_dir {
+        if !dir.is_dir() {
+            return Err(GameError::AssetLoad {
+                path: dir.to_string_lossy().into(),
+                reason: "asset directory not found".into(),
+            });
+        }
+        let path = dir.join(file_name);
+        if path.exists() {
+            let path = path.to_string_lossy().into_owned();
+            let contents = std::fs::read_to_string(&path).map_err(|e| GameError::AssetLoad {
+                path: path.clone(),
+                reason: e.to_string(),
+            })?;
+            return Ok((path, contents));
+        }
+    }
+    let contents = EMBEDDED_ASSETS
+        .iter()
+        .find(|(name, _)| *name == file_name)
+        .map(|(_, contents)| *contents)
+        .ok_or_else(|| GameError::AssetLoad {
+            path: file_name.into(),
+            reason: "no such built-in asset".into(),
+        })?;
+    Ok((format!("<built-in>/{}", file_name), contents.into()))
+}
+
+pub fn get_action_cards() -> Result<Vec<Card>, GameError> {
+    Ok(CardSet::base()?.action_cards())
+}
+
+pub fn get_school_matchups() -> Result<SchoolMatchups, GameError> {
+    load_asset(SCHOOL_MATCHUPS_FILE)
+}
+
+/// A hash of the value's YAML form that stays the same between builds and platforms.
+pub(super) fn stable_hash<T: Serialize>(value: &T) -> u64 {
+    // FNV-1a, since the std hasher is free to change between releases
+    let yaml = serde_yaml::to_string(value).unwrap_or_default();
+    yaml.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Action, Philosopher};
+    use crate::test_utils::get_temp_dir;
+
+    #[test]
+    fn test_load_yaml_missing_file() {
+        let result: Result<SchoolMatchups, GameError> = load_yaml("./assets/missing.yaml");
+        match result {
+            Err(GameError::AssetLoad { path, .. }) => assert_eq!(path, "./assets/missing.yaml"),
+            _ => panic!("expected AssetLoad error"),
+        }
+    }
+
+    #[test]
+    fn test_load_asset_uses_built_in_copy() {
+        let matchups: SchoolMatchups = load_asset(SCHOOL_MATCHUPS_FILE).unwrap();
+        let from_file: SchoolMatchups = load_yaml("./assets/school_matchups.yaml").unwrap();
+        assert_eq!(format!("{:?}", matchups), format!("{:?}", from_file));
+    }
+
+    #[test]
+    fn test_parse_yaml_error_names_file_and_line() {
+        let yaml = "
+- name: Plato
+  school: Rationalist
+  starting_health: lots
+";
+        let result: Result<Vec<Philosopher>, GameError> = parse_yaml("philosophers.yaml", yaml);
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("'philosophers.yaml'"), "{}", message);
+        assert!(message.contains("line 4"), "{}", message);
+    }
+
+    #[test]
+    fn test_load_asset_from_override_dir_falls_back_to_built_in() {
+        let dir = get_temp_dir("assets");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(PHILOSOPHERS_FILE),
+            "- name: Socrates\n  school: Skeptic\n  starting_health: 99\n",
+        )
+        .unwrap();
+        let philosophers: Vec<Philosopher> =
+            load_asset_from(Some(&dir), PHILOSOPHERS_FILE).unwrap();
+        let actions: Vec<Action> = load_asset_from(Some(&dir), ACTIONS_FILE).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(philosophers.len(), 1);
+        assert_eq!(philosophers[0].starting_health, 99);
+        assert!(!actions.is_empty());
+    }
+
+    #[test]
+    fn test_load_asset_from_missing_dir() {
+        let result: Result<SchoolMatchups, GameError> =
+            load_asset_from(Some(Path::new("./no_such_asset_dir")), SCHOOL_MATCHUPS_FILE);
+        assert!(matches!(result, Err(GameError::AssetLoad { .. })));
+    }
 }
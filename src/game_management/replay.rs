@@ -0,0 +1,225 @@
+use crate::entities::CardId;
+use crate::entities::SchoolMatchups;
+use crate::game_error::GameError;
+use crate::game_management::card_pack::CardSet;
+use crate::game_management::helper_functions::{
+    get_school_matchups, is_yaml_file, scan_dir, stable_hash, user_dir,
+};
+use crate::game_management::save_file::{read_versioned_yaml, write_yaml};
+use crate::game_management::{GameBoard, GameConfig};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const REPLAY_VERSION: u32 = 3;
+const REPLAYS_DIR: &str = "replays";
+
+/// Everything needed to rebuild a game: the config (including its seed) and every submitted turn.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Replay {
+    version: u32,
+    // carries the deck rules too, so those are replayed as recorded
+    game_config: GameConfig,
+    // the cards and school matchups the game was played with, which are read from the assets
+    // again on playback and would replay a different game if they changed
+    assets_hash: u64,
+    turns: Vec<Vec<CardId>>,
+}
+impl Replay {
+    pub fn from_board(game_board: &GameBoard) -> Result<Self, GameError> {
+        Ok(Replay {
+            version: REPLAY_VERSION,
+            game_config: game_board.game_config.clone(),
+            assets_hash: installed_assets_hash(&game_board.game_config)?,
+            turns: game_board.turn_history().to_vec(),
+        })
+    }
+
+    pub fn num_turns(&self) -> usize {
+        self.turns.len()
+    }
+
+    pub fn turn(&self, turn_index: usize) -> Option<&[CardId]> {
+        self.turns.get(turn_index).map(|turn| turn.as_slice())
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.game_config.seed()
+    }
+
+    pub fn board_after(&self, num_turns: usize) -> Result<GameBoard, GameError> {
+        let mut game_board = GameBoard::new(Some(self.game_config.clone()))?;
+        for turn in self.turns.iter().take(num_turns) {
+            game_board.process_turn(turn.clone())?;
+        }
+        Ok(game_board)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), GameError> {
+        write_yaml(path, self)
+    }
+
+    /// Saves the replay to its own file in the replays directory and returns the path.
+    pub fn save_new(&self) -> Result<PathBuf, GameError> {
+        self.save_in(&replays_dir())
+    }
+
+    fn save_in(&self, dir: &Path) -> Result<PathBuf, GameError> {
+        std::fs::create_dir_all(dir).map_err(|e| GameError::SaveWrite {
+            path: dir.to_string_lossy().into(),
+            reason: e.to_string(),
+        })?;
+        // the timestamp comes first so the files sort oldest to newest
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis());
+        let path = dir.join(format!("{}_seed_{}.yaml", timestamp, self.seed()));
+        self.save(&path.to_string_lossy())?;
+        Ok(path)
+    }
+
+    /// Loads a replay, refusing it if the installed cards or matchups differ from the recorded ones.
+    pub fn load(path: &str) -> Result<Self, GameError> {
+        Replay::load_checked(path, installed_assets_hash)
+    }
+
+    fn load_checked(
+        path: &str,
+        assets_hash: impl Fn(&GameConfig) -> Result<u64, GameError>,
+    ) -> Result<Self, GameError> {
+        let replay: Replay = read_versioned_yaml(path, REPLAY_VERSION)?;
+        if assets_hash(&replay.game_config)? != replay.assets_hash {
+            return Err(GameError::ReplayAssetsChanged(path.into()));
+        }
+        Ok(replay)
+    }
+}
+
+fn assets_hash(card_set: &CardSet, school_matchups: &SchoolMatchups) -> u64 {
+    stable_hash(&(card_set, school_matchups))
+}
+
+fn installed_assets_hash(game_config: &GameConfig) -> Result<u64, GameError> {
+    let card_set = CardSet::with_enabled_packs(game_config.packs())?;
+    Ok(assets_hash(&card_set, &get_school_matchups()?))
+}
+
+pub fn replays_dir() -> PathBuf {
+    user_dir(REPLAYS_DIR)
+}
+
+/// The saved replays, newest first.
+pub fn available_replays() -> Result<Vec<PathBuf>, GameError> {
+    let mut paths = scan_dir(&replays_dir(), is_yaml_file)?;
+    paths.reverse();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_management::DeckRules;
+    use crate::test_utils::get_temp_dir;
+
+    fn get_example_replay() -> (Replay, GameBoard) {
+        let mut game_board = GameBoard::new(Some(GameConfig::default().with_seed(99))).unwrap();
+        for _ in 0..6 {
+            let Some(turn) = game_board.legal_turns().pop() else {
+                break;
+            };
+            game_board.process_turn(turn).unwrap();
+        }
+        (Replay::from_board(&game_board).unwrap(), game_board)
+    }
+
+    #[test]
+    fn test_replay_rebuilds_final_board() {
+        let (replay, game_board) = get_example_replay();
+        let replayed_board = replay.board_after(replay.num_turns()).unwrap();
+        assert_eq!(format!("{:?}", replayed_board), format!("{:?}", game_board));
+    }
+
+    #[test]
+    fn test_replay_rebuilds_earlier_boards() {
+        let (replay, _) = get_example_replay();
+        let board_after_two = replay.board_after(2).unwrap();
+        assert_eq!(board_after_two.turn_history(), &replay.turns[..2]);
+    }
+
+    #[test]
+    fn test_replay_save_and_load_round_trip() {
+        let (replay, game_board) = get_example_replay();
+        let dir = get_temp_dir("replays");
+        let path = replay.save_in(&dir).unwrap();
+        let loaded_replay = Replay::load(&path.to_string_lossy());
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .ends_with("_seed_99.yaml"));
+        let loaded_replay = loaded_replay.unwrap();
+        let replayed_board = loaded_replay
+            .board_after(loaded_replay.num_turns())
+            .unwrap();
+        assert_eq!(format!("{:?}", replayed_board), format!("{:?}", game_board));
+    }
+
+    // saves the replay, then loads it against the installed assets with `edit` applied
+    fn load_with_edited_assets(
+        replay: &Replay,
+        edit: impl Fn(&mut CardSet, &mut SchoolMatchups),
+    ) -> (String, Result<Replay, GameError>) {
+        let dir = get_temp_dir("replays");
+        let path = replay.save_in(&dir).unwrap();
+        let path = path.to_string_lossy().into_owned();
+        let loaded_replay = Replay::load_checked(&path, |game_config| {
+            let mut card_set = CardSet::with_enabled_packs(game_config.packs())?;
+            let mut school_matchups = get_school_matchups()?;
+            edit(&mut card_set, &mut school_matchups);
+            Ok(assets_hash(&card_set, &school_matchups))
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+        (path, loaded_replay)
+    }
+
+    #[test]
+    fn test_replay_refuses_changed_cards() {
+        let (replay, _) = get_example_replay();
+        let (path, loaded_replay) = load_with_edited_assets(&replay, |card_set, _| {
+            card_set.philosophers[0].starting_health += 1
+        });
+        assert_eq!(
+            loaded_replay.unwrap_err(),
+            GameError::ReplayAssetsChanged(path)
+        );
+    }
+
+    #[test]
+    fn test_replay_refuses_changed_school_matchups() {
+        let (replay, _) = get_example_replay();
+        let (path, loaded_replay) = load_with_edited_assets(&replay, |_, school_matchups| {
+            school_matchups.advantage_percent += 10
+        });
+        assert_eq!(
+            loaded_replay.unwrap_err(),
+            GameError::ReplayAssetsChanged(path)
+        );
+    }
+
+    #[test]
+    fn test_replay_keeps_recorded_deck_rules() {
+        let deck_rules = DeckRules {
+            max_copies: 1,
+            ..DeckRules::default()
+        };
+        let game_board = GameBoard::new(Some(
+            GameConfig::default().with_deck_rules(deck_rules.clone()),
+        ))
+        .unwrap();
+        let replay = Replay::from_board(&game_board).unwrap();
+        let (_, loaded_replay) = load_with_edited_assets(&replay, |_, _| {});
+        let replayed_board = loaded_replay.unwrap().board_after(0).unwrap();
+        assert_eq!(replayed_board.game_config.deck_rules(), &deck_rules);
+    }
+}
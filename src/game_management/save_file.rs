@@ -0,0 +1,179 @@
+use crate::game_error::GameError;
+use crate::game_management::helper_functions::user_dir;
+use crate::game_management::GameBoard;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::PathBuf;
+
+// bump whenever a change to the board's serialized layout would break older saves
+const SAVE_VERSION: u32 = 2;
+const SAVE_FILE: &str = "infinite_debate_save.yaml";
+
+#[derive(Deserialize, Serialize)]
+struct SaveFile {
+    version: u32,
+    game_board: GameBoard,
+}
+
+#[derive(Deserialize)]
+struct VersionHeader {
+    version: u32,
+}
+
+pub(super) fn write_yaml<T: Serialize>(path: &str, contents: &T) -> Result<(), GameError> {
+    let to_save_error = |reason: String| GameError::SaveWrite {
+        path: path.into(),
+        reason,
+    };
+    let contents = serde_yaml::to_string(contents).map_err(|e| to_save_error(e.to_string()))?;
+    std::fs::write(path, contents).map_err(|e| to_save_error(e.to_string()))
+}
+
+pub(super) fn read_versioned_yaml<T: DeserializeOwned>(
+    path: &str,
+    expected_version: u32,
+) -> Result<T, GameError> {
+    let to_load_error = |reason: String| GameError::SaveLoad {
+        path: path.into(),
+        reason,
+    };
+    let contents = std::fs::read_to_string(path).map_err(|e| to_load_error(e.to_string()))?;
+    // check the version on its own first so old files get a clearer error than a parse failure
+    let header: VersionHeader =
+        serde_yaml::from_str(&contents).map_err(|e| to_load_error(e.to_string()))?;
+    if header.version != expected_version {
+        return Err(GameError::SaveVersion {
+            path: path.into(),
+            found: header.version,
+            expected: expected_version,
+        });
+    }
+    serde_yaml::from_str(&contents).map_err(|e| to_load_error(e.to_string()))
+}
+
+/// Where Save, Continue and `--continue` keep the saved game.
+pub fn save_path() -> PathBuf {
+    user_dir(SAVE_FILE)
+}
+
+pub fn save_game(game_board: &GameBoard, path: &str) -> Result<(), GameError> {
+    let save_file = SaveFile {
+        version: SAVE_VERSION,
+        game_board: game_board.clone(),
+    };
+    write_yaml(path, &save_file)
+}
+
+/// Loads a saved game, refusing boards that are finished or can't be played on.
+pub fn load_game(path: &str) -> Result<GameBoard, GameError> {
+    let save_file: SaveFile = read_versioned_yaml(path, SAVE_VERSION)?;
+    if let Some(reason) = save_file.game_board.unplayable_reason() {
+        return Err(GameError::SaveLoad {
+            path: path.into(),
+            reason,
+        });
+    }
+    Ok(save_file.game_board)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::CardId;
+    use crate::test_utils::get_example_board;
+
+    fn get_save_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!(
+                "infinite_debate_{}_{}.yaml",
+                name,
+                std::process::id()
+            ))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = get_save_path("round_trip");
+        let mut game_board = get_example_board();
+        game_board.process_turn(vec![CardId(0), CardId(1)]).unwrap();
+        save_game(&game_board, &path).unwrap();
+        let loaded_board = load_game(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(format!("{:?}", loaded_board), format!("{:?}", game_board));
+    }
+
+    #[test]
+    fn test_load_game_rejects_other_versions() {
+        let path = get_save_path("old_version");
+        std::fs::write(&path, "version: 0\ngame_board: {}\n").unwrap();
+        let result = load_game(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            result.unwrap_err(),
+            GameError::SaveVersion {
+                path,
+                found: 0,
+                expected: SAVE_VERSION
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_game_rejects_corrupted_saves() {
+        let path = get_save_path("corrupted");
+        std::fs::write(
+            &path,
+            format!("version: {}\ngame_board: [1, 2\n", SAVE_VERSION),
+        )
+        .unwrap();
+        let result = load_game(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(GameError::SaveLoad { .. })));
+    }
+
+    // saves the example board with part of its yaml swapped out, then tries to load it
+    fn load_edited_save(name: &str, from: &str, to: &str) -> Result<GameBoard, GameError> {
+        let path = get_save_path(name);
+        save_game(&get_example_board(), &path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains(from));
+        std::fs::write(&path, contents.replacen(from, to, 1)).unwrap();
+        let result = load_game(&path);
+        std::fs::remove_file(&path).unwrap();
+        result
+    }
+
+    #[test]
+    fn test_load_game_rejects_finished_games() {
+        let result = load_edited_save(
+            "game_over",
+            "game_phase: Player1Turn",
+            "game_phase: GameOver",
+        );
+        assert!(matches!(
+            result,
+            Err(GameError::SaveLoad { reason, .. }) if reason == "the game is already over"
+        ));
+    }
+
+    #[test]
+    fn test_load_game_rejects_inconsistent_hands() {
+        let result = load_edited_save("hand_size", "max_cards_in_hand: 7", "max_cards_in_hand: 1");
+        assert!(matches!(
+            result,
+            Err(GameError::SaveLoad { reason, .. }) if reason.contains("but the hand size is 1")
+        ));
+    }
+
+    #[test]
+    fn test_save_path_sits_with_the_other_user_files() {
+        assert_eq!(save_path().file_name().unwrap(), SAVE_FILE);
+    }
+
+    #[test]
+    fn test_load_game_missing_file() {
+        let result = load_game("./missing_save.yaml");
+        assert!(matches!(result, Err(GameError::SaveLoad { .. })));
+    }
+}
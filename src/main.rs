@@ -1,14 +1,200 @@
+mod card_validation;
+mod controllers;
 mod entities;
+mod game_error;
 mod game_management;
 mod player;
 mod rendering;
+mod simulation;
 mod test_utils;
 
+use controllers::ControllerKind;
+use game_management::{DeckRules, Decklist, GameBoard, GameConfig, GameOverReason, Seat};
+use ratatui::DefaultTerminal;
+use rendering::{MainMenu, MenuAction, StatusMessage};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+fn get_arg<T: FromStr>(args: &[String], flag: &str, default: T) -> Result<T, String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(i) => args
+            .get(i + 1)
+            .ok_or(format!("missing value after {}", flag))?
+            .parse()
+            .map_err(|_| format!("invalid value for {}", flag)),
+        None => Ok(default),
+    }
+}
+
+fn simulate(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let config = simulation::SimulationConfig::new(
+        get_arg(args, "--games", 1000)?,
+        get_arg(args, "--player1", ControllerKind::GreedyAi)?,
+        get_arg(args, "--player2", ControllerKind::GreedyAi)?,
+        get_arg(args, "--seed", 0)?,
+    )?;
+    let report = simulation::run_simulation(&config)?;
+    print!("{}", report);
+    Ok(())
+}
+
+fn get_theme(args: &[String]) -> Result<rendering::Theme, Box<dyn std::error::Error>> {
+    let theme: String = get_arg(args, "--theme", "dark".to_string())?;
+    Ok(rendering::Theme::load(&theme)?)
+}
+
+fn validate_cards() -> Result<(), Box<dyn std::error::Error>> {
+    let report = card_validation::validate_cards();
+    print!("{}", report);
+    if !report.problems.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn view_replay(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    // the newest replay when no file is given
+    let path = match args.get(2).filter(|arg| !arg.starts_with("--")) {
+        Some(path) => PathBuf::from(path),
+        None => game_management::available_replays()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("no replays in {}", game_management::replays_dir().display()))?,
+    };
+    let replay = game_management::Replay::load(&path.to_string_lossy())?;
+    let replay_viewer = rendering::ReplayViewer::new(replay)?.with_theme(get_theme(args)?);
+    let mut terminal = ratatui::init();
+    let viewer_result = replay_viewer.run(&mut terminal);
+    ratatui::restore();
+    viewer_result
+}
+
+fn game_over_message(reason: Option<GameOverReason>, seed: u64) -> String {
+    match reason {
+        Some(reason) => format!("Game Over: {} (seed {})", reason, seed),
+        None => format!("Game Over (seed {})", seed),
+    }
+}
+
+fn get_decklist(
+    args: &[String],
+    flag: &str,
+) -> Result<Option<Decklist>, Box<dyn std::error::Error>> {
+    let path: String = get_arg(args, flag, String::new())?;
+    if path.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Decklist::load(Path::new(&path))?))
+}
+
+// starts a game straight from the command line flags, skipping the menu
+fn play(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let player_1 = get_arg(args, "--player1", ControllerKind::Human)?;
+    let player_2 = get_arg(args, "--player2", ControllerKind::Human)?;
+    let game_board = if args.iter().any(|arg| arg == "--continue") {
+        game_management::load_game(&game_management::save_path().to_string_lossy())?
+    } else {
+        let game_config = GameConfig::default();
+        let seed = get_arg(args, "--seed", game_config.seed())?;
+        let packs: String = get_arg(args, "--packs", String::new())?;
+        let packs = packs
+            .split(',')
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect();
+        let game_config = game_config
+            .with_seed(seed)
+            .with_packs(packs)
+            .with_deck_rules(DeckRules::load()?)
+            .with_decklist(Seat::Player1, get_decklist(args, "--deck1")?)
+            .with_decklist(Seat::Player2, get_decklist(args, "--deck2")?);
+        GameBoard::new(Some(game_config))?
+    };
+    let seed = game_board.game_config.seed();
+    let game_app =
+        rendering::GameApp::new(game_board, player_1, player_2).with_theme(get_theme(args)?);
+    let mut terminal = ratatui::init();
+    let app_result = game_app.run(&mut terminal);
+    ratatui::restore();
+    let game_result = app_result?;
+    println!("{}", game_over_message(game_result.game_over_reason, seed));
+    println!("Replay written to {}", game_result.replay_path.display());
+    Ok(())
+}
+
+fn show_menu(
+    mut main_menu: MainMenu,
+    terminal: &mut DefaultTerminal,
+) -> Result<(), Box<dyn std::error::Error>> {
+    loop {
+        match main_menu.run(terminal)? {
+            MenuAction::StartGame {
+                game_board,
+                player_1,
+                player_2,
+            } => {
+                let seed = game_board.game_config.seed();
+                let game_result = rendering::GameApp::new(*game_board, player_1, player_2)
+                    .with_theme(main_menu.theme().clone())
+                    .run(terminal)?;
+                main_menu.show_message(StatusMessage::Info(format!(
+                    "{}, replay written to {}",
+                    game_over_message(game_result.game_over_reason, seed),
+                    game_result.replay_path.display()
+                )));
+            }
+            MenuAction::ViewReplay(replay) => match rendering::ReplayViewer::new(replay) {
+                Ok(replay_viewer) => replay_viewer
+                    .with_theme(main_menu.theme().clone())
+                    .run(terminal)?,
+                Err(e) => main_menu.show_message(StatusMessage::Error(e.to_string())),
+            },
+            MenuAction::BuildDeck {
+                card_set,
+                rules,
+                decklist,
+                path,
+            } => {
+                rendering::DeckBuilder::new(card_set, rules, decklist, path)
+                    .with_theme(main_menu.theme().clone())
+                    .run(terminal)?;
+                main_menu.reload_decklists();
+            }
+            MenuAction::Quit => return Ok(()),
+        }
+    }
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let game_app = rendering::GameApp::new();
-    let terminal = ratatui::init();
-    let app_result = game_app.run(terminal);
+    let args: Vec<String> = std::env::args().collect();
+    let asset_dir: String = get_arg(&args, "--assets", String::new())?;
+    if !asset_dir.is_empty() {
+        game_management::set_asset_dir(asset_dir.into());
+    }
+    match args.get(1).map(|arg| arg.as_str()) {
+        Some("simulate") => return simulate(&args),
+        Some("replay") => return view_replay(&args),
+        Some("validate-cards") => return validate_cards(),
+        _ => (),
+    }
+    if args.iter().any(|arg| {
+        [
+            "--continue",
+            "--player1",
+            "--player2",
+            "--seed",
+            "--packs",
+            "--deck1",
+            "--deck2",
+        ]
+        .contains(&arg.as_str())
+    }) {
+        return play(&args);
+    }
+    let theme_name = get_arg(&args, "--theme", "dark".to_string())?;
+    let main_menu = MainMenu::new(theme_name.clone(), rendering::Theme::load(&theme_name)?);
+    let mut terminal = ratatui::init();
+    let menu_result = show_menu(main_menu, &mut terminal);
     ratatui::restore();
-    println!("Game Over");
-    Ok(app_result?)
+    menu_result
 }
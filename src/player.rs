@@ -1,5 +1,9 @@
+pub mod discard_pile;
+pub mod graveyard;
 pub mod player_hand;
 pub mod remaining_deck;
 
+pub use discard_pile::DiscardPile;
+pub use graveyard::Graveyard;
 pub use player_hand::PlayerHand;
 pub use remaining_deck::RemainingDeck;
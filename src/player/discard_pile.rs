@@ -0,0 +1,53 @@
+use crate::entities::CardInstance;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DiscardPile {
+    cards: Vec<CardInstance>,
+}
+impl DiscardPile {
+    pub fn add_card(&mut self, card: CardInstance) {
+        self.cards.push(card);
+    }
+
+    pub fn cards(&self) -> &[CardInstance] {
+        &self.cards
+    }
+
+    pub fn take_all(&mut self) -> Vec<CardInstance> {
+        std::mem::take(&mut self.cards)
+    }
+
+    pub fn num_cards(&self) -> usize {
+        self.cards.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::CardId;
+    use crate::test_utils::get_example_cards;
+
+    #[test]
+    fn test_add_cards() {
+        let mut discard_pile = DiscardPile::default();
+        assert_eq!(discard_pile.num_cards(), 0);
+        for card in get_example_cards(0) {
+            discard_pile.add_card(card);
+        }
+        assert_eq!(discard_pile.num_cards(), 3);
+        assert_eq!(discard_pile.cards().last().unwrap().id, CardId(2));
+    }
+
+    #[test]
+    fn test_take_all() {
+        let mut discard_pile = DiscardPile::default();
+        for card in get_example_cards(0) {
+            discard_pile.add_card(card);
+        }
+        let taken = discard_pile.take_all();
+        assert_eq!(taken.len(), 3);
+        assert_eq!(discard_pile.num_cards(), 0);
+    }
+}
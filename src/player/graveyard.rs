@@ -0,0 +1,65 @@
+use crate::entities::{Card, CardInstance, InPlayPhilosopher};
+use crate::game_error::GameError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Graveyard {
+    philosophers: Vec<CardInstance>,
+}
+impl Graveyard {
+    pub fn add_philosopher(&mut self, philosopher: CardInstance) -> Result<(), GameError> {
+        match philosopher.card {
+            Card::InPlayPhilosopher(_) => {
+                self.philosophers.push(philosopher);
+                Ok(())
+            }
+            _ => Err(GameError::NotAPhilosopher),
+        }
+    }
+
+    pub fn philosophers(&self) -> impl Iterator<Item = &InPlayPhilosopher> {
+        self.philosophers
+            .iter()
+            .filter_map(|card| match &card.card {
+                Card::InPlayPhilosopher(p) => Some(p),
+                _ => None,
+            })
+    }
+
+    pub fn num_cards(&self) -> usize {
+        self.philosophers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::CardId;
+    use crate::test_utils::{get_example_cards, get_example_in_play_philosopher};
+
+    #[test]
+    fn test_add_philosopher() {
+        let mut graveyard = Graveyard::default();
+        let result = graveyard.add_philosopher(CardInstance {
+            id: CardId(7),
+            card: Card::InPlayPhilosopher(get_example_in_play_philosopher("dead".into(), 1)),
+        });
+        assert!(result.is_ok());
+        assert_eq!(graveyard.num_cards(), 1);
+        assert_eq!(
+            graveyard.philosophers().next().unwrap().philosopher.name,
+            "dead"
+        );
+    }
+
+    #[test]
+    fn test_add_non_philosopher() {
+        let mut graveyard = Graveyard::default();
+        let action = get_example_cards(0).remove(0);
+        assert_eq!(
+            graveyard.add_philosopher(action),
+            Err(GameError::NotAPhilosopher)
+        );
+        assert_eq!(graveyard.num_cards(), 0);
+    }
+}
@@ -1,48 +1,83 @@
-use crate::entities::{Card, InPlayPhilosopher};
+use crate::entities::{Card, CardId, CardInstance, InPlayPhilosopher};
+use crate::game_error::GameError;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PlayerHand {
     pub active_philosopher: Option<InPlayPhilosopher>,
-    pub inactive_cards: Vec<Box<Card>>,
+    pub active_philosopher_id: Option<CardId>,
+    pub inactive_cards: Vec<CardInstance>,
     pub max_cards_in_hand: u8,
 }
 impl PlayerHand {
-    pub fn add_cards_to_hand(
-        &mut self,
-        cards: Vec<Box<Card>>,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn add_cards_to_hand(&mut self, cards: Vec<CardInstance>) -> Result<(), GameError> {
         self.inactive_cards.extend(cards);
         Ok(())
     }
 
-    pub fn play_philosopher(
-        &mut self,
-        philosopher_card: Card,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn owns_card(&self, card_id: &CardId) -> bool {
+        self.inactive_cards.iter().any(|card| &card.id == card_id)
+    }
+
+    pub fn get_card(&self, card_id: &CardId) -> Option<&CardInstance> {
+        self.inactive_cards.iter().find(|card| &card.id == card_id)
+    }
+
+    pub fn take_card(&mut self, card_id: &CardId) -> Result<CardInstance, GameError> {
+        let position = self
+            .inactive_cards
+            .iter()
+            .position(|card| &card.id == card_id)
+            .ok_or(GameError::NotOwned(*card_id))?;
+        Ok(self.inactive_cards.remove(position))
+    }
+
+    pub fn play_philosopher(&mut self, philosopher_card: CardInstance) -> Result<(), GameError> {
+        let new_philosopher = match philosopher_card.card {
+            Card::Philosopher(philosopher) => InPlayPhilosopher::new(philosopher),
+            Card::InPlayPhilosopher(in_play_philos) => in_play_philos,
+            Card::Action(_) => return Err(GameError::NotAPhilosopher),
+        };
         if let Some(active_philosopher) = self.active_philosopher.take() {
-            self.inactive_cards
-                .push(Box::new(Card::InPlayPhilosopher(active_philosopher)));
+            let active_id = self.active_philosopher_id.ok_or(GameError::MissingCardId)?;
+            self.inactive_cards.push(CardInstance {
+                id: active_id,
+                card: Card::InPlayPhilosopher(active_philosopher),
+            });
         }
-        match philosopher_card {
-            Card::Philosopher(philosopher) => {
-                self.active_philosopher = Some(InPlayPhilosopher::new(philosopher));
-                return Ok(());
-            }
-            Card::InPlayPhilosopher(in_play_philos) => {
-                self.active_philosopher = Some(in_play_philos);
-                return Ok(());
+        self.active_philosopher = Some(new_philosopher);
+        self.active_philosopher_id = Some(philosopher_card.id);
+        Ok(())
+    }
+
+    pub fn remove_dead_philosophers(&mut self) -> Vec<CardInstance> {
+        let mut dead_philosophers = vec![];
+        if self
+            .active_philosopher
+            .as_ref()
+            .is_some_and(|p| p.is_dead())
+        {
+            if let (Some(philosopher), Some(id)) = (
+                self.active_philosopher.take(),
+                self.active_philosopher_id.take(),
+            ) {
+                dead_philosophers.push(CardInstance {
+                    id,
+                    card: Card::InPlayPhilosopher(philosopher),
+                });
             }
-            Card::Action(_) => return Err("Action card played as philosopher".into()),
         }
+        let (dead_in_hand, remaining): (Vec<CardInstance>, Vec<CardInstance>) = self
+            .inactive_cards
+            .drain(..)
+            .partition(|card| matches!(&card.card, Card::InPlayPhilosopher(p) if p.is_dead()));
+        self.inactive_cards = remaining;
+        dead_philosophers.extend(dead_in_hand);
+        dead_philosophers
     }
 
-    pub fn num_available_slots_in_hand(&self) -> u8 {
-        self.max_cards_in_hand.saturating_sub(
-            self.inactive_cards
-                .len()
-                .try_into()
-                .expect("can't get num available slots in hand"),
-        )
+    pub fn num_available_slots_in_hand(&self) -> usize {
+        usize::from(self.max_cards_in_hand).saturating_sub(self.inactive_cards.len())
     }
 }
 
@@ -57,6 +92,7 @@ mod tests {
     fn test_player_hand_creation_no_cards() {
         let player_hand = PlayerHand {
             active_philosopher: None,
+            active_philosopher_id: None,
             inactive_cards: vec![],
             max_cards_in_hand: 7,
         };
@@ -66,7 +102,7 @@ mod tests {
 
     #[test]
     fn test_player_hand_creation_philosopher_card() {
-        let player_hand = get_populated_player_hand(16);
+        let player_hand = get_populated_player_hand(16, 0);
         assert!(player_hand.active_philosopher.is_some());
         assert_eq!(player_hand.inactive_cards.len(), 3)
     }
@@ -75,11 +111,15 @@ mod tests {
     fn play_philosopher_with_empty_spot() {
         let mut player_hand = PlayerHand {
             active_philosopher: None,
+            active_philosopher_id: None,
             inactive_cards: vec![],
             max_cards_in_hand: 7,
         };
         let example_philosopher = Philosopher::new("test".into(), CoreSchool::Skeptic, 16);
-        let result = player_hand.play_philosopher(Card::Philosopher(example_philosopher));
+        let result = player_hand.play_philosopher(CardInstance {
+            id: CardId(1),
+            card: Card::Philosopher(example_philosopher),
+        });
         assert!(result.is_ok());
         assert!(player_hand.active_philosopher.is_some());
         assert_eq!(
@@ -92,12 +132,16 @@ mod tests {
     fn play_in_play_philosopher_with_empty_spot() {
         let mut player_hand = PlayerHand {
             active_philosopher: None,
+            active_philosopher_id: None,
             inactive_cards: vec![],
             max_cards_in_hand: 7,
         };
         let example_philosopher = Philosopher::new("test".into(), CoreSchool::Skeptic, 16);
         let ex_in_play_philos = InPlayPhilosopher::new(example_philosopher);
-        let result = player_hand.play_philosopher(Card::InPlayPhilosopher(ex_in_play_philos));
+        let result = player_hand.play_philosopher(CardInstance {
+            id: CardId(1),
+            card: Card::InPlayPhilosopher(ex_in_play_philos),
+        });
         assert!(result.is_ok());
         assert!(player_hand.active_philosopher.is_some());
         assert_eq!(
@@ -112,18 +156,24 @@ mod tests {
         let ex_in_play_philos1 = InPlayPhilosopher::new(example_philos1);
         let mut player_hand = PlayerHand {
             active_philosopher: Some(ex_in_play_philos1),
+            active_philosopher_id: Some(CardId(0)),
             inactive_cards: vec![],
             max_cards_in_hand: 7,
         };
         let new_philos = Philosopher::new("expected".into(), CoreSchool::Skeptic, 16);
         let expected_in_play_philos = InPlayPhilosopher::new(new_philos);
-        let result = player_hand.play_philosopher(Card::InPlayPhilosopher(expected_in_play_philos));
+        let result = player_hand.play_philosopher(CardInstance {
+            id: CardId(1),
+            card: Card::InPlayPhilosopher(expected_in_play_philos),
+        });
         assert!(result.is_ok());
         assert_eq!(
             player_hand.active_philosopher.unwrap().philosopher.name,
             "expected"
         );
-        assert_ne!(player_hand.inactive_cards.len(), 0)
+        assert_eq!(player_hand.active_philosopher_id, Some(CardId(1)));
+        assert_eq!(player_hand.inactive_cards.len(), 1);
+        assert_eq!(player_hand.inactive_cards[0].id, CardId(0));
     }
 
     #[test]
@@ -132,27 +182,34 @@ mod tests {
         let ex_in_play_philos1 = InPlayPhilosopher::new(example_philos1);
         let mut player_hand = PlayerHand {
             active_philosopher: Some(ex_in_play_philos1),
+            active_philosopher_id: Some(CardId(0)),
             inactive_cards: vec![],
             max_cards_in_hand: 7,
         };
         let new_philos = Philosopher::new("expected".into(), CoreSchool::Skeptic, 16);
-        let result = player_hand.play_philosopher(Card::Philosopher(new_philos));
+        let result = player_hand.play_philosopher(CardInstance {
+            id: CardId(1),
+            card: Card::Philosopher(new_philos),
+        });
         assert!(result.is_ok());
         assert_eq!(
             player_hand.active_philosopher.unwrap().philosopher.name,
             "expected"
         );
-        assert_ne!(player_hand.inactive_cards.len(), 0)
+        assert_eq!(player_hand.active_philosopher_id, Some(CardId(1)));
+        assert_eq!(player_hand.inactive_cards.len(), 1);
+        assert_eq!(player_hand.inactive_cards[0].id, CardId(0));
     }
 
     #[test]
     fn test_add_cards_to_empty() {
         let mut player_hand = PlayerHand {
             active_philosopher: None,
+            active_philosopher_id: None,
             inactive_cards: vec![],
             max_cards_in_hand: 7,
         };
-        let new_cards = get_example_cards();
+        let new_cards = get_example_cards(0);
         let num_new_cards = new_cards.len();
         let result = player_hand.add_cards_to_hand(new_cards);
         assert!(result.is_ok());
@@ -161,9 +218,9 @@ mod tests {
 
     #[test]
     fn test_add_cards_to_existing_cards() {
-        let mut player_hand = get_populated_player_hand(16);
+        let mut player_hand = get_populated_player_hand(16, 0);
         let num_existing_cards = player_hand.inactive_cards.len();
-        let new_cards = get_example_cards();
+        let new_cards = get_example_cards(10);
         let num_new_cards = new_cards.len();
         let result = player_hand.add_cards_to_hand(new_cards);
         assert!(result.is_ok());
@@ -175,8 +232,8 @@ mod tests {
 
     #[test]
     fn test_num_available_slots_in_hand() {
-        let player_hand = get_populated_player_hand(16);
-        let num_existing_cards: u8 = player_hand.inactive_cards.len().try_into().unwrap();
+        let player_hand = get_populated_player_hand(16, 0);
+        let num_existing_cards = player_hand.inactive_cards.len();
         assert_eq!(
             player_hand.num_available_slots_in_hand(),
             8 - num_existing_cards
@@ -185,11 +242,69 @@ mod tests {
 
     #[test]
     fn test_num_available_slots_in_hand_no_slots() {
-        let mut player_hand = get_populated_player_hand(16);
-        let _ = player_hand.add_cards_to_hand(get_example_cards());
-        let _ = player_hand.add_cards_to_hand(get_example_cards());
-        let _ = player_hand.add_cards_to_hand(get_example_cards());
-        let _ = player_hand.add_cards_to_hand(get_example_cards());
+        let mut player_hand = get_populated_player_hand(16, 0);
+        let _ = player_hand.add_cards_to_hand(get_example_cards(10));
+        let _ = player_hand.add_cards_to_hand(get_example_cards(20));
+        let _ = player_hand.add_cards_to_hand(get_example_cards(30));
+        let _ = player_hand.add_cards_to_hand(get_example_cards(40));
         assert_eq!(player_hand.num_available_slots_in_hand(), 0);
     }
+
+    #[test]
+    fn test_play_action_as_philosopher_leaves_hand_untouched() {
+        let mut player_hand = get_populated_player_hand(16, 0);
+        let action = get_example_cards(10).remove(0);
+        let result = player_hand.play_philosopher(action);
+        assert_eq!(result, Err(GameError::NotAPhilosopher));
+        assert!(player_hand.active_philosopher.is_some());
+        assert_eq!(player_hand.inactive_cards.len(), 3);
+    }
+
+    #[test]
+    fn test_take_card() {
+        let mut player_hand = get_populated_player_hand(16, 0);
+        assert!(player_hand.owns_card(&CardId(1)));
+        let card = player_hand.take_card(&CardId(1)).unwrap();
+        assert_eq!(card.id, CardId(1));
+        assert!(!player_hand.owns_card(&CardId(1)));
+        assert_eq!(player_hand.inactive_cards.len(), 2);
+    }
+
+    #[test]
+    fn test_take_card_not_owned() {
+        let mut player_hand = get_populated_player_hand(16, 0);
+        let result = player_hand.take_card(&CardId(42));
+        assert!(matches!(result, Err(GameError::NotOwned(CardId(42)))));
+        assert_eq!(player_hand.inactive_cards.len(), 3);
+    }
+
+    #[test]
+    fn test_remove_dead_philosophers() {
+        let mut player_hand = get_populated_player_hand(16, 0);
+        let mut dead_in_hand = InPlayPhilosopher::new(Philosopher::new(
+            "dead_in_hand".into(),
+            CoreSchool::Skeptic,
+            2,
+        ));
+        dead_in_hand.apply_direct_damage(5);
+        player_hand.inactive_cards.push(CardInstance {
+            id: CardId(9),
+            card: Card::InPlayPhilosopher(dead_in_hand),
+        });
+        assert_eq!(player_hand.remove_dead_philosophers().len(), 1);
+        assert!(!player_hand.owns_card(&CardId(9)));
+        assert!(player_hand.active_philosopher.is_some());
+
+        player_hand
+            .active_philosopher
+            .as_mut()
+            .unwrap()
+            .apply_direct_damage(20);
+        let removed = player_hand.remove_dead_philosophers();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].id, CardId(3));
+        assert!(player_hand.active_philosopher.is_none());
+        assert!(player_hand.active_philosopher_id.is_none());
+        assert_eq!(player_hand.inactive_cards.len(), 3);
+    }
 }
@@ -1,26 +1,46 @@
-use crate::entities::Card;
-use rand::{rng, RngCore};
-use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use crate::entities::CardInstance;
+use crate::game_error::GameError;
+use rand::{rng, rngs::StdRng, seq::SliceRandom, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemainingDeck {
-    cards: Vec<Box<Card>>,
+    cards: Vec<CardInstance>,
+    // every shuffle draws the seed for the next one, so a seeded deck stays repeatable
+    shuffle_seed: u64,
 }
 impl RemainingDeck {
-    pub fn new(mut cards: Vec<Box<Card>>, seed: Option<u64>) -> Self {
-        let mut rng: Box<dyn RngCore> = match seed {
-            Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
-            None => Box::new(rng()),
+    pub fn new(cards: Vec<CardInstance>, seed: Option<u64>) -> Self {
+        let mut remaining_deck = RemainingDeck {
+            cards,
+            shuffle_seed: seed.unwrap_or_else(|| rng().next_u64()),
         };
-        cards.shuffle(&mut rng);
-        RemainingDeck { cards }
+        remaining_deck.shuffle();
+        remaining_deck
     }
-    pub fn num_remaining_cards(&self) -> u8 {
-        self.cards.len().try_into().unwrap()
+
+    fn shuffle(&mut self) {
+        let mut rng = StdRng::seed_from_u64(self.shuffle_seed);
+        self.cards.shuffle(&mut rng);
+        self.shuffle_seed = rng.next_u64();
+    }
+
+    pub fn shuffle_in(&mut self, cards: Vec<CardInstance>) {
+        self.cards.extend(cards);
+        self.shuffle();
+    }
+
+    pub fn reshuffle(&mut self, seed: u64) {
+        self.shuffle_seed = seed;
+        self.shuffle();
     }
-    pub fn draw_new_cards(&mut self, n: u8) -> Result<Vec<Box<Card>>, Box<dyn std::error::Error>> {
-        let n: u8 = n.min(self.num_remaining_cards());
-        let selected_cards: Vec<Box<Card>> = self.cards.drain(0..n as usize).collect();
+
+    pub fn num_remaining_cards(&self) -> usize {
+        self.cards.len()
+    }
+    pub fn draw_new_cards(&mut self, n: usize) -> Result<Vec<CardInstance>, GameError> {
+        let n = n.min(self.num_remaining_cards());
+        let selected_cards: Vec<CardInstance> = self.cards.drain(0..n).collect();
         Ok(selected_cards)
     }
 }
@@ -34,10 +54,10 @@ mod tests {
 
     #[test]
     fn test_new_remaining_deck_with_seed() {
-        let cards = get_example_cards();
+        let cards = get_example_cards(0);
         let remaining_deck = RemainingDeck::new(cards, Some(42));
         assert_ne!(
-            format!("{:?}", get_example_cards()),
+            format!("{:?}", get_example_cards(0)),
             format!("{:?}", remaining_deck.cards)
         );
         assert_ne!(remaining_deck.num_remaining_cards(), 0);
@@ -45,15 +65,15 @@ mod tests {
 
     #[test]
     fn test_new_remaining_deck_without_seed() {
-        let cards = get_example_cards();
+        let cards = get_example_cards(0);
         let remaining_deck = RemainingDeck::new(cards, None);
         assert_ne!(remaining_deck.num_remaining_cards(), 0);
     }
 
     #[test]
     fn test_draw_new_cards_with_cards_left() {
-        let cards = get_example_cards();
-        let num_original_cards: u8 = cards.len().try_into().unwrap();
+        let cards = get_example_cards(0);
+        let num_original_cards = cards.len();
         let mut remaining_deck = RemainingDeck::new(cards, None);
         assert_eq!(remaining_deck.num_remaining_cards(), num_original_cards);
         let drawn_cards = remaining_deck
@@ -75,8 +95,8 @@ mod tests {
 
     #[test]
     fn test_draw_new_cards_with_not_enough_left() {
-        let cards = get_example_cards();
-        let num_original_cards: u8 = cards.len().try_into().unwrap();
+        let cards = get_example_cards(0);
+        let num_original_cards = cards.len();
         let mut remaining_deck = RemainingDeck::new(cards, None);
         assert_eq!(remaining_deck.num_remaining_cards(), num_original_cards);
         let drawn_cards = remaining_deck
@@ -85,4 +105,27 @@ mod tests {
         assert!(drawn_cards.len() < 64);
         assert_eq!(remaining_deck.num_remaining_cards(), 0);
     }
+
+    #[test]
+    fn test_shuffle_in() {
+        let mut remaining_deck = RemainingDeck::new(vec![], None);
+        remaining_deck.shuffle_in(get_example_cards(0));
+        assert_eq!(remaining_deck.num_remaining_cards(), 3);
+        let drawn_cards = remaining_deck
+            .draw_new_cards(3)
+            .expect("should have been able to draw cards");
+        assert_eq!(drawn_cards.len(), 3);
+    }
+
+    #[test]
+    fn test_shuffle_in_with_seed_is_repeatable() {
+        let mut remaining_deck_1 = RemainingDeck::new(vec![], Some(42));
+        let mut remaining_deck_2 = RemainingDeck::new(vec![], Some(42));
+        remaining_deck_1.shuffle_in(get_example_cards(0));
+        remaining_deck_2.shuffle_in(get_example_cards(0));
+        assert_eq!(
+            format!("{:?}", remaining_deck_1.cards),
+            format!("{:?}", remaining_deck_2.cards)
+        );
+    }
 }
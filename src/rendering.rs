@@ -1,3 +1,12 @@
+pub mod deck_builder;
 pub mod game_app;
+pub mod main_menu;
+pub mod replay_viewer;
+pub mod theme;
+pub mod widgets;
 
-pub use game_app::GameApp;
+pub use deck_builder::DeckBuilder;
+pub use game_app::{GameApp, StatusMessage};
+pub use main_menu::{MainMenu, MenuAction};
+pub use replay_viewer::ReplayViewer;
+pub use theme::Theme;
@@ -0,0 +1,623 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{BarChart, Block, Paragraph, Widget},
+    DefaultTerminal,
+};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::entities::{AbilityType, Card, CoreSchool};
+use crate::game_management::{decks_dir, CardSet, DeckRules, Decklist};
+use crate::rendering::main_menu::cycle;
+use crate::rendering::widgets::CardWidget;
+use crate::rendering::{StatusMessage, Theme};
+
+const SCHOOLS: [CoreSchool; 3] = [
+    CoreSchool::Rationalist,
+    CoreSchool::Empiricist,
+    CoreSchool::Skeptic,
+];
+const SCHOOL_FILTERS: [Option<CoreSchool>; 4] = [
+    None,
+    Some(CoreSchool::Rationalist),
+    Some(CoreSchool::Empiricist),
+    Some(CoreSchool::Skeptic),
+];
+const TYPE_FILTERS: [Option<CardType>; 3] =
+    [None, Some(CardType::Philosopher), Some(CardType::Action)];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CardType {
+    Philosopher,
+    Action,
+}
+impl CardType {
+    fn of(card: &Card) -> Self {
+        match card {
+            Card::Action(_) => CardType::Action,
+            Card::Philosopher(_) | Card::InPlayPhilosopher(_) => CardType::Philosopher,
+        }
+    }
+}
+
+// "All" when there is no filter
+fn filter_name<T: std::fmt::Debug>(filter: Option<T>) -> String {
+    filter.map_or("All".into(), |value| format!("{:?}", value))
+}
+
+/// Edits a decklist against the full card catalog and saves it to the decks directory.
+pub struct DeckBuilder {
+    exit: bool,
+    card_set: CardSet,
+    catalog: Vec<Card>,
+    rules: DeckRules,
+    decklist: Decklist,
+    school_filter: Option<CoreSchool>,
+    type_filter: Option<CardType>,
+    selected_card: usize,
+    renaming: bool,
+    decks_dir: PathBuf,
+    // the file this deck was loaded from or last saved to, the only one it replaces without asking
+    saved_path: Option<PathBuf>,
+    // the decklist as it was loaded or last saved, to spot unsaved changes
+    saved_decklist: Decklist,
+    confirm_overwrite: bool,
+    confirm_discard: bool,
+    status_message: Option<StatusMessage>,
+    theme: Theme,
+}
+impl DeckBuilder {
+    /// `saved_path` is the file `decklist` was loaded from, none for a new deck.
+    pub fn new(
+        card_set: CardSet,
+        rules: DeckRules,
+        decklist: Decklist,
+        saved_path: Option<PathBuf>,
+    ) -> Self {
+        let mut catalog = card_set.philosopher_cards();
+        catalog.extend(card_set.action_cards());
+        DeckBuilder {
+            exit: false,
+            card_set,
+            catalog,
+            rules,
+            saved_decklist: decklist.clone(),
+            decklist,
+            school_filter: None,
+            type_filter: None,
+            selected_card: 0,
+            renaming: false,
+            decks_dir: decks_dir(),
+            saved_path,
+            confirm_overwrite: false,
+            confirm_discard: false,
+            status_message: None,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<(), Box<dyn std::error::Error>> {
+        while !self.exit {
+            terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            }
+        }
+        Ok(())
+    }
+
+    fn filtered_cards(&self) -> Vec<&Card> {
+        self.catalog
+            .iter()
+            .filter(|card| {
+                self.school_filter
+                    .is_none_or(|school| *card.school() == school)
+            })
+            .filter(|card| {
+                self.type_filter
+                    .is_none_or(|card_type| CardType::of(card) == card_type)
+            })
+            .collect()
+    }
+
+    fn selected(&self) -> Option<&Card> {
+        self.filtered_cards().get(self.selected_card).copied()
+    }
+
+    fn handle_key(&mut self, key: event::KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        self.status_message = None;
+        let confirm_overwrite = std::mem::take(&mut self.confirm_overwrite);
+        let confirm_discard = std::mem::take(&mut self.confirm_discard);
+        if self.renaming {
+            match key.code {
+                KeyCode::Char(c) => self.decklist.name.push(c),
+                KeyCode::Backspace => {
+                    self.decklist.name.pop();
+                }
+                KeyCode::Enter | KeyCode::Esc if self.decklist.name.trim().is_empty() => {
+                    self.status_message =
+                        Some(StatusMessage::Error("the deck needs a name".into()));
+                }
+                KeyCode::Enter | KeyCode::Esc => self.renaming = false,
+                _ => {}
+            }
+            return;
+        }
+        let num_cards = self.filtered_cards().len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                if self.decklist == self.saved_decklist || confirm_discard {
+                    self.exit = true;
+                } else {
+                    self.confirm_discard = true;
+                    self.status_message = Some(StatusMessage::Error(
+                        "the deck has unsaved changes, press [Esc] again to discard them".into(),
+                    ));
+                }
+            }
+            KeyCode::Up | KeyCode::Char('w') if num_cards > 0 => {
+                self.selected_card = (self.selected_card + num_cards - 1) % num_cards;
+            }
+            KeyCode::Down | KeyCode::Char('s') if num_cards > 0 => {
+                self.selected_card = (self.selected_card + 1) % num_cards;
+            }
+            KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('+') => {
+                if let Some(name) = self.selected().map(|card| card.name().to_string()) {
+                    self.decklist.add(&name);
+                }
+            }
+            KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('-') => {
+                if let Some(name) = self.selected().map(|card| card.name().to_string()) {
+                    self.decklist.remove(&name);
+                }
+            }
+            KeyCode::Char('f') => {
+                self.school_filter = cycle(&SCHOOL_FILTERS, self.school_filter, true);
+                self.selected_card = 0;
+            }
+            KeyCode::Char('t') => {
+                self.type_filter = cycle(&TYPE_FILTERS, self.type_filter, true);
+                self.selected_card = 0;
+            }
+            KeyCode::Char('n') => self.renaming = true,
+            KeyCode::Enter => self.save(confirm_overwrite),
+            _ => {}
+        }
+    }
+
+    fn save(&mut self, confirm_overwrite: bool) {
+        let violations = self.decklist.rule_violations(&self.card_set, &self.rules);
+        if let Some(violation) = violations.first() {
+            self.status_message = Some(StatusMessage::Error(format!(
+                "can't save until the deck is legal: {}",
+                violation
+            )));
+            return;
+        }
+        // another deck, or one whose name only differs in punctuation, may already use the file,
+        // as may a deck this one was renamed to
+        let path = self.decklist.path_in(&self.decks_dir);
+        if path.exists() && self.saved_path.as_ref() != Some(&path) && !confirm_overwrite {
+            self.confirm_overwrite = true;
+            self.status_message = Some(StatusMessage::Error(format!(
+                "{} already exists, press [Enter] again to replace it",
+                path.display()
+            )));
+            return;
+        }
+        self.status_message = Some(match self.decklist.save_to(&self.decks_dir) {
+            Ok(path) => {
+                let message = format!("Saved deck to {}", path.display());
+                self.saved_path = Some(path);
+                self.saved_decklist = self.decklist.clone();
+                StatusMessage::Info(message)
+            }
+            Err(e) => StatusMessage::Error(e.to_string()),
+        });
+    }
+
+    // each card in the decklist with how many copies it has, skipping unknown names
+    fn deck_cards(&self) -> impl Iterator<Item = (Card, usize)> + '_ {
+        self.decklist.cards.iter().filter_map(|entry| {
+            self.card_set
+                .card(&entry.card)
+                .map(|card| (card, entry.count))
+        })
+    }
+
+    fn school_counts(&self) -> Vec<(CoreSchool, usize)> {
+        SCHOOLS
+            .iter()
+            .map(|school| {
+                let count = self
+                    .deck_cards()
+                    .filter(|(card, _)| card.school() == school)
+                    .map(|(_, count)| count)
+                    .sum();
+                (*school, count)
+            })
+            .collect()
+    }
+
+    // copies in the deck for each value `value_of` gives a card, lowest value first
+    fn curve(&self, value_of: impl Fn(&Card) -> Option<u8>) -> Vec<(String, u64)> {
+        let mut curve: BTreeMap<u8, u64> = BTreeMap::new();
+        for (card, count) in self.deck_cards() {
+            if let Some(value) = value_of(&card) {
+                *curve.entry(value).or_default() += count as u64;
+            }
+        }
+        curve
+            .into_iter()
+            .map(|(value, count)| (value.to_string(), count))
+            .collect()
+    }
+
+    fn health_curve(&self) -> Vec<(String, u64)> {
+        self.curve(|card| match card {
+            Card::Philosopher(philosopher) => Some(philosopher.starting_health),
+            _ => None,
+        })
+    }
+
+    fn damage_curve(&self) -> Vec<(String, u64)> {
+        self.curve(|card| match card {
+            Card::Action(action) => match action.ability_type {
+                AbilityType::Damage { damage, .. } => Some(damage),
+                AbilityType::Heal { .. } => None,
+            },
+            _ => None,
+        })
+    }
+}
+
+impl DeckBuilder {
+    fn render_catalog(&self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<Line> = self
+            .filtered_cards()
+            .iter()
+            .enumerate()
+            .map(|(i, card)| {
+                let count = self.decklist.count(card.name());
+                let style = if i == self.selected_card {
+                    Style::default()
+                        .fg(self.theme.highlight_text)
+                        .bg(self.theme.hovered_bg)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(self.theme.school(card.school()))
+                };
+                Line::styled(format!("{}x {}", count, card.name()), style)
+            })
+            .collect();
+        // keep the selected card on screen in long catalogs
+        let visible_rows = usize::from(area.height.saturating_sub(2)).max(1);
+        let scroll = self.selected_card.saturating_sub(visible_rows - 1);
+        Paragraph::new(lines)
+            .scroll((scroll as u16, 0))
+            .block(Block::bordered().title(format!(
+                "Cards: {} / {}",
+                filter_name(self.school_filter),
+                filter_name(self.type_filter)
+            )))
+            .render(area, buf);
+    }
+
+    fn render_summary(&self, area: Rect, buf: &mut Buffer) {
+        let mut lines = vec![
+            Line::raw(if self.renaming {
+                format!("Deck: {}▏", self.decklist.name)
+            } else {
+                format!("Deck: {}", self.decklist.name)
+            }),
+            Line::raw(format!(
+                "{} cards ({} to {}, up to {} copies, {} philosophers or more)",
+                self.decklist.num_cards(),
+                self.rules.min_deck_size,
+                self.rules.max_deck_size,
+                self.rules.max_copies,
+                self.rules.min_philosophers
+            )),
+            Line::from(
+                self.school_counts()
+                    .into_iter()
+                    .map(|(school, count)| {
+                        Span::styled(
+                            format!("{:?} {}  ", school, count),
+                            Style::default().fg(self.theme.school(&school)),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            Line::raw(""),
+        ];
+        let violations = self.decklist.rule_violations(&self.card_set, &self.rules);
+        if violations.is_empty() {
+            lines.push(Line::styled(
+                "Ready to play",
+                Style::default().fg(self.theme.info),
+            ));
+        }
+        lines.extend(
+            violations
+                .into_iter()
+                .map(|violation| Line::styled(violation, Style::default().fg(self.theme.error))),
+        );
+
+        let block = Block::bordered().title("Deck");
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+        let [info_area, health_area, damage_area] = Layout::vertical([
+            Constraint::Length(lines.len() as u16),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(inner_area);
+        Paragraph::new(lines).render(info_area, buf);
+        for (title, curve, chart_area) in [
+            ("Health curve", self.health_curve(), health_area),
+            ("Damage curve", self.damage_curve(), damage_area),
+        ] {
+            let data: Vec<(&str, u64)> = curve
+                .iter()
+                .map(|(label, count)| (label.as_str(), *count))
+                .collect();
+            BarChart::default()
+                .block(Block::bordered().title(title))
+                .bar_width(3)
+                .bar_gap(1)
+                .bar_style(Style::default().fg(self.theme.muted))
+                .data(data.as_slice())
+                .render(chart_area, buf);
+        }
+    }
+
+    fn controls(&self) -> &'static str {
+        if self.renaming {
+            "Type the deck name, [Enter] when done"
+        } else {
+            "Use ↓↑ to move, ←→ to remove/add, [f] school, [t] type, [n] rename, [Enter] to save, [Esc] to go back"
+        }
+    }
+}
+impl Widget for &mut DeckBuilder {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [main_area, status_area, controls_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+        let [catalog_area, card_area, summary_area] = Layout::horizontal([
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+        ])
+        .areas(main_area);
+
+        self.render_catalog(catalog_area, buf);
+        match self.selected() {
+            Some(card) => CardWidget::new(card, &self.theme)
+                .block(Block::bordered())
+                .render(card_area, buf),
+            None => Paragraph::new("No cards match the filters")
+                .centered()
+                .block(Block::bordered())
+                .render(card_area, buf),
+        }
+        self.render_summary(summary_area, buf);
+
+        match &self.status_message {
+            Some(StatusMessage::Error(message)) => Paragraph::new(message.as_str())
+                .style(Style::default().fg(self.theme.error))
+                .centered()
+                .render(status_area, buf),
+            Some(StatusMessage::Info(message)) => Paragraph::new(message.as_str())
+                .style(Style::default().fg(self.theme.info))
+                .centered()
+                .render(status_area, buf),
+            None => {}
+        }
+        Paragraph::new(self.controls())
+            .centered()
+            .render(controls_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir;
+
+    fn press(deck_builder: &mut DeckBuilder, code: KeyCode) {
+        deck_builder.handle_key(event::KeyEvent::from(code))
+    }
+
+    fn get_example_deck_builder() -> DeckBuilder {
+        DeckBuilder::new(
+            CardSet::base().unwrap(),
+            DeckRules::default(),
+            Decklist::new("Test Deck".into()),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_filters_narrow_the_catalog() {
+        let mut deck_builder = get_example_deck_builder();
+        assert_eq!(deck_builder.filtered_cards().len(), 9);
+        press(&mut deck_builder, KeyCode::Char('f'));
+        assert_eq!(deck_builder.school_filter, Some(CoreSchool::Rationalist));
+        assert_eq!(deck_builder.filtered_cards().len(), 3);
+        press(&mut deck_builder, KeyCode::Char('t'));
+        assert_eq!(
+            deck_builder
+                .filtered_cards()
+                .iter()
+                .map(|card| card.name())
+                .collect::<Vec<_>>(),
+            ["Plato"]
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_selected_card() {
+        let mut deck_builder = get_example_deck_builder();
+        press(&mut deck_builder, KeyCode::Down);
+        press(&mut deck_builder, KeyCode::Right);
+        press(&mut deck_builder, KeyCode::Right);
+        press(&mut deck_builder, KeyCode::Left);
+        assert_eq!(deck_builder.decklist.count("Aristotle"), 1);
+        assert_eq!(deck_builder.health_curve(), [("12".to_string(), 1)]);
+        assert_eq!(
+            deck_builder.school_counts(),
+            [
+                (CoreSchool::Rationalist, 0),
+                (CoreSchool::Empiricist, 1),
+                (CoreSchool::Skeptic, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_illegal_deck_is_not_saved() {
+        let mut deck_builder = get_example_deck_builder();
+        press(&mut deck_builder, KeyCode::Enter);
+        assert!(matches!(
+            deck_builder.status_message,
+            Some(StatusMessage::Error(ref message)) if message.contains("0 cards but at least 10")
+        ));
+    }
+
+    #[test]
+    fn test_rename_and_render() {
+        let mut deck_builder = get_example_deck_builder();
+        press(&mut deck_builder, KeyCode::Char('n'));
+        press(&mut deck_builder, KeyCode::Backspace);
+        press(&mut deck_builder, KeyCode::Char('x'));
+        press(&mut deck_builder, KeyCode::Enter);
+        assert_eq!(deck_builder.decklist.name, "Test Decx");
+        let area = Rect::new(0, 0, 120, 30);
+        let mut buf = Buffer::empty(area);
+        (&mut deck_builder).render(area, &mut buf);
+        let rendered: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("Deck: Test Decx"));
+        assert!(rendered.contains("Health curve"));
+        assert!(rendered.contains("Plato"));
+    }
+
+    #[test]
+    fn test_rename_needs_a_name() {
+        let mut deck_builder = get_example_deck_builder();
+        press(&mut deck_builder, KeyCode::Char('n'));
+        for _ in 0.."Test Deck".len() {
+            press(&mut deck_builder, KeyCode::Backspace);
+        }
+        press(&mut deck_builder, KeyCode::Char(' '));
+        press(&mut deck_builder, KeyCode::Enter);
+        assert!(deck_builder.renaming);
+        assert!(matches!(
+            deck_builder.status_message,
+            Some(StatusMessage::Error(ref message)) if message == "the deck needs a name"
+        ));
+    }
+
+    // one of every card, plus a second Plato
+    fn add_legal_cards(deck_builder: &mut DeckBuilder) {
+        for _ in 0..deck_builder.catalog.len() {
+            press(deck_builder, KeyCode::Right);
+            press(deck_builder, KeyCode::Down);
+        }
+        press(deck_builder, KeyCode::Right);
+    }
+
+    #[test]
+    fn test_save_asks_before_replacing_another_deck() {
+        let mut deck_builder = get_example_deck_builder();
+        deck_builder.decks_dir = get_temp_dir("decks");
+        add_legal_cards(&mut deck_builder);
+        deck_builder.decklist.name = "Doubt & Reason".into();
+        let other_deck = Decklist {
+            name: "Doubt | Reason".into(),
+            ..deck_builder.decklist.clone()
+        };
+        let path = other_deck.save_to(&deck_builder.decks_dir).unwrap();
+
+        press(&mut deck_builder, KeyCode::Enter);
+        let kept_name = Decklist::load(&path).unwrap().name;
+        press(&mut deck_builder, KeyCode::Enter);
+        let replaced_name = Decklist::load(&path).unwrap().name;
+        press(&mut deck_builder, KeyCode::Enter);
+        let resaved = deck_builder.status_message.take();
+        std::fs::remove_dir_all(&deck_builder.decks_dir).unwrap();
+
+        assert_eq!(kept_name, "Doubt | Reason");
+        assert_eq!(replaced_name, "Doubt & Reason");
+        assert!(matches!(
+            resaved,
+            Some(StatusMessage::Info(ref message)) if message.starts_with("Saved deck to")
+        ));
+    }
+
+    #[test]
+    fn test_renamed_deck_asks_before_replacing_existing_file() {
+        let mut deck_builder = get_example_deck_builder();
+        add_legal_cards(&mut deck_builder);
+        let decks_dir = get_temp_dir("decks");
+        let loaded_path = deck_builder.decklist.save_to(&decks_dir).unwrap();
+        let other_path = Decklist::new("Other".into()).save_to(&decks_dir).unwrap();
+        let mut deck_builder = DeckBuilder::new(
+            deck_builder.card_set,
+            deck_builder.rules,
+            deck_builder.decklist,
+            Some(loaded_path),
+        );
+        deck_builder.decks_dir = decks_dir;
+
+        press(&mut deck_builder, KeyCode::Enter);
+        let resaved = deck_builder.status_message.take();
+        deck_builder.decklist.name = "Other".into();
+        press(&mut deck_builder, KeyCode::Enter);
+        let kept_name = Decklist::load(&other_path).unwrap().name;
+        let kept_cards = Decklist::load(&other_path).unwrap().cards;
+        std::fs::remove_dir_all(&deck_builder.decks_dir).unwrap();
+
+        assert!(matches!(
+            resaved,
+            Some(StatusMessage::Info(ref message)) if message.starts_with("Saved deck to")
+        ));
+        assert_eq!(kept_name, "Other");
+        assert!(kept_cards.is_empty());
+        assert!(deck_builder.confirm_overwrite);
+    }
+
+    #[test]
+    fn test_esc_asks_before_discarding_unsaved_changes() {
+        let mut deck_builder = get_example_deck_builder();
+        press(&mut deck_builder, KeyCode::Right);
+        press(&mut deck_builder, KeyCode::Esc);
+        assert!(!deck_builder.exit);
+        assert!(matches!(
+            deck_builder.status_message,
+            Some(StatusMessage::Error(ref message)) if message.contains("unsaved changes")
+        ));
+        press(&mut deck_builder, KeyCode::Esc);
+        assert!(deck_builder.exit);
+
+        let mut unchanged = get_example_deck_builder();
+        press(&mut unchanged, KeyCode::Esc);
+        assert!(unchanged.exit);
+    }
+}
@@ -1,38 +1,63 @@
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
-    layout::{Alignment, Constraint, Layout, Rect},
-    style::{
-        palette::tailwind::{GREEN, SLATE},
-        Modifier, Style,
-    },
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
     text::Line,
-    widgets::{Block, ListState, Paragraph, Widget, Wrap},
+    widgets::{Block, ListState, Paragraph, Widget},
     DefaultTerminal,
 };
+use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::game_management::GameBoard;
+use crate::controllers::{ControllerKind, Player};
+use crate::game_error::GameError;
+use crate::game_management::{self, GameBoard, GameEvent, GameOverReason, Replay, Seat};
+use crate::rendering::widgets::{CardWidget, PhilosopherWidget};
+use crate::rendering::Theme;
 use crate::{
-    entities::{Card, InPlayPhilosopher},
+    entities::{Card, CardId},
     game_management::GamePhase,
 };
 
+const AI_TURN_DELAY: Duration = Duration::from_millis(800);
+
 #[derive(Copy, Clone, PartialEq)]
 pub enum CardSelectionState {
     Selected,
     NotSelected,
 }
 
+pub enum StatusMessage {
+    Info(String),
+    Error(String),
+}
+
+/// How a game ended and where its replay was written.
+pub struct GameResult {
+    pub game_over_reason: Option<GameOverReason>,
+    pub replay_path: PathBuf,
+}
+
 pub struct GameApp {
     exit: bool,
     game_board: GameBoard,
     current_round: u32,
     current_card_state: ListState,
     selected_cards: Vec<CardSelectionState>,
+    status_message: Option<StatusMessage>,
+    // none shows both players' events
+    combat_log_filter: Option<Seat>,
+    // how many entries the combat log is scrolled back from the newest one
+    combat_log_scroll: usize,
+    theme: Theme,
+    // in hot-seat games the board stays hidden until the next player takes over
+    handoff_to: Option<Seat>,
+    player_1_controller: Option<Box<dyn Player>>,
+    player_2_controller: Option<Box<dyn Player>>,
 }
 impl GameApp {
-    pub fn new() -> Self {
-        let game_board = GameBoard::new(None);
+    pub fn new(game_board: GameBoard, player_1: ControllerKind, player_2: ControllerKind) -> Self {
         let num_cards = game_board.game_config.max_cards_in_hand();
         GameApp {
             exit: false,
@@ -40,33 +65,199 @@ impl GameApp {
             current_round: 0,
             current_card_state: ListState::default(),
             selected_cards: vec![CardSelectionState::NotSelected; num_cards.into()],
+            status_message: None,
+            combat_log_filter: None,
+            combat_log_scroll: 0,
+            theme: Theme::default(),
+            handoff_to: None,
+            player_1_controller: player_1.build(None),
+            player_2_controller: player_2.build(None),
         }
     }
 
-    pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn run(
+        mut self,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<GameResult, Box<dyn std::error::Error>> {
+        self.check_for_game_over();
         while !self.exit {
             terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
-            if let Event::Key(key) = event::read()? {
+            if self.is_ai_turn() {
+                // leave the board on screen for a moment so AI turns can be followed
+                if event::poll(AI_TURN_DELAY)? {
+                    if let Event::Key(key) = event::read()? {
+                        self.handle_key(key);
+                    }
+                } else {
+                    // the search runs on this thread and input waits until it's done,
+                    // so put up a notice first instead of leaving a frozen board
+                    self.show_ai_thinking();
+                    terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
+                    self.take_ai_turn();
+                }
+            } else if let Event::Key(key) = event::read()? {
                 self.handle_key(key);
             };
         }
-        Ok(())
+        let replay_path = Replay::from_board(&self.game_board)?.save_new()?;
+        Ok(GameResult {
+            game_over_reason: self.game_board.game_over_reason().cloned(),
+            replay_path,
+        })
+    }
+
+    fn controller(&self, seat: &Seat) -> Option<&dyn Player> {
+        match seat {
+            Seat::Player1 => self.player_1_controller.as_deref(),
+            Seat::Player2 => self.player_2_controller.as_deref(),
+        }
+    }
+
+    fn is_hot_seat(&self) -> bool {
+        self.player_1_controller.is_none() && self.player_2_controller.is_none()
+    }
+
+    fn is_ai_turn(&self) -> bool {
+        self.game_board
+            .game_phase()
+            .active_seat()
+            .is_some_and(|seat| self.controller(&seat).is_some())
+    }
+
+    fn show_ai_thinking(&mut self) {
+        let Some(seat) = self.game_board.game_phase().active_seat() else {
+            return;
+        };
+        if let Some(controller) = self.controller(&seat) {
+            self.status_message = Some(StatusMessage::Info(format!(
+                "{} ({}) is thinking…",
+                controller.name(),
+                seat
+            )));
+        }
+    }
+
+    fn take_ai_turn(&mut self) {
+        let Some(seat) = self.game_board.game_phase().active_seat() else {
+            return;
+        };
+        let controller = match seat {
+            Seat::Player1 => self.player_1_controller.as_mut(),
+            Seat::Player2 => self.player_2_controller.as_mut(),
+        };
+        let Some(controller) = controller else {
+            return;
+        };
+        let turn = controller.choose_turn(&self.game_board);
+        let controller_name = controller.name().to_string();
+        self.status_message = self
+            .game_board
+            .process_turn(turn)
+            .err()
+            .map(|e| StatusMessage::Error(format!("{} {}", controller_name, e)));
+        self.check_for_game_over();
     }
 
     fn handle_key(&mut self, key: event::KeyEvent) {
         if key.kind != KeyEventKind::Press {
             return;
         }
+        if self.handoff_to.take().is_some() {
+            return;
+        }
+        // the combat log can be browsed while an AI is playing too
         match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => self.exit = true,
-            KeyCode::Left | KeyCode::Char('a') => self.select_previous(),
-            KeyCode::Right | KeyCode::Char('d') => self.select_next(),
-            KeyCode::Up | KeyCode::Char('w') => self
-                .toggle_card_selection()
-                .expect("Couldn't toggle card selection"),
-            KeyCode::Enter => self.submit_card_selections(),
+            KeyCode::Char('f') => {
+                self.cycle_combat_log_filter();
+                return;
+            }
+            KeyCode::PageUp => {
+                self.combat_log_scroll += 1;
+                return;
+            }
+            KeyCode::PageDown => {
+                self.combat_log_scroll = self.combat_log_scroll.saturating_sub(1);
+                return;
+            }
             _ => {}
         }
+        if self.is_ai_turn() {
+            if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                self.exit = true;
+            }
+            return;
+        }
+        self.status_message = None;
+        let result = match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.exit = true;
+                Ok(())
+            }
+            KeyCode::Left | KeyCode::Char('a') => {
+                self.select_previous();
+                Ok(())
+            }
+            KeyCode::Right | KeyCode::Char('d') => {
+                self.select_next();
+                Ok(())
+            }
+            KeyCode::Up | KeyCode::Char('w') => self.toggle_card_selection(),
+            KeyCode::Enter => self.submit_card_selections(),
+            KeyCode::Char('s') => self.save_game(),
+            KeyCode::Char('l') => self.load_game(),
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            self.status_message = Some(StatusMessage::Error(e.to_string()));
+        }
+    }
+
+    fn save_game(&mut self) -> Result<(), GameError> {
+        let path = game_management::save_path();
+        game_management::save_game(&self.game_board, &path.to_string_lossy())?;
+        self.status_message = Some(StatusMessage::Info(format!(
+            "Game saved to {}",
+            path.display()
+        )));
+        Ok(())
+    }
+
+    fn load_game(&mut self) -> Result<(), GameError> {
+        let path = game_management::save_path();
+        self.game_board = game_management::load_game(&path.to_string_lossy())?;
+        self.reset_card_selection_state();
+        self.status_message = Some(StatusMessage::Info(format!(
+            "Game loaded from {}",
+            path.display()
+        )));
+        Ok(())
+    }
+
+    fn cycle_combat_log_filter(&mut self) {
+        self.combat_log_filter = match self.combat_log_filter {
+            None => Some(Seat::Player1),
+            Some(Seat::Player1) => Some(Seat::Player2),
+            Some(Seat::Player2) => None,
+        };
+        self.combat_log_scroll = 0;
+    }
+
+    fn combat_log_lines(&self) -> Vec<String> {
+        let event_log = self.game_board.event_log();
+        let entries: Vec<_> = match self.combat_log_filter {
+            Some(seat) => event_log.involving(seat).collect(),
+            None => event_log.entries().iter().collect(),
+        };
+        entries
+            .into_iter()
+            .filter(|entry| !matches!(entry.event, GameEvent::PhaseChanged { .. }))
+            .map(|entry| format!("{}: {}", entry.turn + 1, entry.event))
+            .collect()
     }
 
     fn select_previous(&mut self) {
@@ -89,128 +280,238 @@ impl GameApp {
         self.current_card_state.select(Some(new_selection));
     }
 
-    fn toggle_card_selection(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn toggle_card_selection(&mut self) -> Result<(), GameError> {
         if let Some(i) = self.current_card_state.selected() {
-            let selected_count = self
-                .selected_cards
-                .iter()
-                .filter(|&&s| s == CardSelectionState::Selected)
-                .count();
-
             if self.selected_cards[i] == CardSelectionState::Selected {
                 self.selected_cards[i] = CardSelectionState::NotSelected;
-            } else if selected_count
-                < self
-                    .game_board
-                    .game_config
-                    .max_cards_played_per_turn()
-                    .into()
+                return Ok(());
+            }
+            self.selected_cards[i] = CardSelectionState::Selected;
+            // only the card limit matters while selecting, the rest is checked on submit
+            if let Err(e @ GameError::TooManyCards { .. }) =
+                self.game_board.validate_turn(&self.selected_card_ids())
             {
-                self.selected_cards[i] = CardSelectionState::Selected;
+                self.selected_cards[i] = CardSelectionState::NotSelected;
+                return Err(e);
             }
         }
         Ok(())
     }
 
-    fn reset_card_selection_state(&mut self) {
-        let num_cards = self.game_board.game_config.max_cards_in_hand();
-        self.selected_cards = vec![CardSelectionState::NotSelected; num_cards.into()];
-    }
-
-    fn has_no_philosopher(
-        selected_cards: &Vec<&Card>,
-        current_philosopher: Option<&InPlayPhilosopher>,
-    ) -> bool {
-        current_philosopher.is_none()
-            && !selected_cards
-                .iter()
-                .any(|card| matches!(card, Card::Philosopher(_) | Card::InPlayPhilosopher(_)))
-    }
-
-    fn check_for_game_over(&mut self) {
-        match self.game_board.game_phase() {
-            GamePhase::GameOver => {
-                self.exit = true;
-            }
-            _ => (),
-        }
-    }
-
-    fn submit_card_selections(&mut self) {
-        let (active_hand, _active_deck) = self
-            .game_board
-            .active_player_data()
-            .expect("can't get active player data");
-
-        let selected_cards: Vec<&Card> = active_hand
+    fn selected_card_ids(&self) -> Vec<CardId> {
+        let Some(active_seat) = self.game_board.game_phase().active_seat() else {
+            return vec![];
+        };
+        self.game_board
+            .hand(&active_seat)
             .inactive_cards
             .iter()
             .enumerate()
             .filter_map(|(i, card)| {
                 if self.selected_cards.get(i) == Some(&CardSelectionState::Selected) {
-                    Some(card.as_ref())
+                    Some(card.id)
                 } else {
                     None
                 }
             })
-            .collect();
+            .collect()
+    }
 
-        if GameApp::has_no_philosopher(&selected_cards, active_hand.active_philosopher.as_ref()) {
-            // can't play turn unless there's an active philosopher, or one being played
-            return;
+    fn reset_card_selection_state(&mut self) {
+        let num_cards = self.game_board.game_config.max_cards_in_hand();
+        self.selected_cards = vec![CardSelectionState::NotSelected; num_cards.into()];
+    }
+
+    fn check_for_game_over(&mut self) {
+        if self.game_board.game_phase() == &GamePhase::GameOver {
+            self.exit = true;
         }
+    }
 
-        if !selected_cards.is_empty() {
-            let owned_cards: Vec<Card> = selected_cards.into_iter().cloned().collect();
-            self.game_board
-                .process_turn(owned_cards)
-                .expect("couldn't process turn");
+    fn submit_card_selections(&mut self) -> Result<(), GameError> {
+        let card_ids = self.selected_card_ids();
+        if !card_ids.is_empty() {
+            self.game_board.process_turn(card_ids)?;
+            if self.is_hot_seat() {
+                self.handoff_to = self.game_board.game_phase().active_seat();
+            }
         }
         self.reset_card_selection_state();
         self.check_for_game_over();
+        Ok(())
     }
 }
 
 impl GameApp {
+    // what the previous player did, without anything from either hand
+    fn handoff_summary(&self) -> Vec<String> {
+        let Some(last_turn) = self.game_board.turn_history().len().checked_sub(1) else {
+            return vec![];
+        };
+        self.game_board
+            .event_log()
+            .for_turn(last_turn)
+            .filter(|entry| !matches!(entry.event, GameEvent::PhaseChanged { .. }))
+            .map(|entry| entry.event.to_string())
+            .collect()
+    }
+
+    fn render_handoff(&self, seat: Seat, area: Rect, buf: &mut Buffer) {
+        let summary = self.handoff_summary();
+        let [_, handoff_area, _] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(summary.len() as u16 + 5),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+        let mut lines = vec![
+            Line::styled(
+                format!("Pass to {} — press any key", seat),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(""),
+            Line::styled(
+                format!("{}'s turn:", seat.other()),
+                Style::default().fg(self.theme.muted),
+            ),
+        ];
+        lines.extend(summary.into_iter().map(Line::raw));
+        Paragraph::new(lines)
+            .centered()
+            .block(Block::bordered())
+            .render(handoff_area, buf);
+    }
+
     fn render_footer(&self, area: Rect, buf: &mut Buffer) {
+        let [log_area, controls_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(area);
+        match &self.status_message {
+            Some(StatusMessage::Error(message)) => {
+                Paragraph::new(format!("Can't do that: {}", message))
+                    .style(Style::default().fg(self.theme.error))
+                    .centered()
+                    .render(log_area, buf)
+            }
+            Some(StatusMessage::Info(message)) => Paragraph::new(message.as_str())
+                .style(Style::default().fg(self.theme.info))
+                .centered()
+                .render(log_area, buf),
+            None => {
+                let last_log_entry = self
+                    .game_board
+                    .event_log()
+                    .last()
+                    .map(|entry| entry.event.to_string())
+                    .unwrap_or_default();
+                Paragraph::new(last_log_entry)
+                    .centered()
+                    .render(log_area, buf);
+            }
+        }
         Paragraph::new(
-            format!("Round: {} --- Use ↓↑ to move, ← to unselect all, → to add/remove card, [Enter] to end turn", self.current_round),
+            format!(
+                "Round: {} --- Out of cards: {} --- Seed: {} --- Use ↓↑ to move, ← to unselect all, → to add/remove card, [Enter] to end turn, [s]ave, [l]oad, [f]ilter log, [PgUp]/[PgDn] to scroll log",
+                self.current_round,
+                self.game_board.game_config.out_of_cards_rule(),
+                self.game_board.game_config.seed()
+            ),
         )
         .centered()
-        .render(area, buf);
+        .render(controls_area, buf);
     }
 
-    fn render_opponent_philosophers(&mut self, area: Rect, buf: &mut Buffer) {
-        //  TODO: put actual logic here
-        let block = Block::bordered().title(Line::raw("Opponent Philosophers").centered());
-        let (inactive_hand, _inactive_deck) = self
+    fn zone_summary(&self, seat: &Seat) -> Line<'static> {
+        let deck = self.game_board.remaining_deck(seat);
+        let discard_pile = self.game_board.discard_pile(seat);
+        let graveyard = self.game_board.graveyard(seat);
+        let last_discarded = match discard_pile.cards().last().map(|card| &card.card) {
+            Some(Card::Action(action)) => format!(" (last: {})", action.name()),
+            _ => String::new(),
+        };
+        let fallen: Vec<&str> = graveyard
+            .philosophers()
+            .map(|p| p.philosopher.name.as_str())
+            .collect();
+        let fallen = if fallen.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", fallen.join(", "))
+        };
+        Line::raw(format!(
+            "Deck: {} | Discard: {}{} | Graveyard: {}{}",
+            deck.num_remaining_cards(),
+            discard_pile.num_cards(),
+            last_discarded,
+            graveyard.num_cards(),
+            fallen
+        ))
+        .centered()
+    }
+
+    // the seat whose hand is shown: the active human, or the only human while an AI plays
+    fn viewing_seat(&self) -> Seat {
+        // a finished game is shown from player 1's side
+        let active_seat = self
             .game_board
-            .inactive_player_data()
-            .expect("can't get inactive player data");
-        Paragraph::new(format!("{:?}", inactive_hand.active_philosopher))
-            .centered()
+            .game_phase()
+            .active_seat()
+            .unwrap_or(Seat::Player1);
+        let other_seat = active_seat.other();
+        if self.controller(&active_seat).is_some() && self.controller(&other_seat).is_none() {
+            other_seat
+        } else {
+            active_seat
+        }
+    }
+
+    fn render_opponent_philosophers(&mut self, area: Rect, buf: &mut Buffer) {
+        let opponent_seat = self.viewing_seat().other();
+        let block = Block::bordered()
+            .title(Line::raw("Opponent Philosophers").centered())
+            .title_bottom(self.zone_summary(&opponent_seat));
+        let opponent_hand = self.game_board.hand(&opponent_seat);
+        PhilosopherWidget::new(opponent_hand.active_philosopher.as_ref(), &self.theme)
             .block(block)
             .render(area, buf);
     }
 
     fn render_player_philosophers(&mut self, area: Rect, buf: &mut Buffer) {
-        //  TODO: put actual logic here
-        let block = Block::bordered().title(Line::raw("Player Philosophers").centered());
-        let (active_hand, _active_deck) = self
-            .game_board
-            .active_player_data()
-            .expect("can't get active player data");
-        Paragraph::new(format!("{:?}", active_hand.active_philosopher))
-            .centered()
+        let viewing_seat = self.viewing_seat();
+        let block = Block::bordered()
+            .title(Line::raw("Player Philosophers").centered())
+            .title_bottom(self.zone_summary(&viewing_seat));
+        let player_hand = self.game_board.hand(&viewing_seat);
+        PhilosopherWidget::new(player_hand.active_philosopher.as_ref(), &self.theme)
             .block(block)
             .render(area, buf);
     }
 
+    fn render_combat_log(&mut self, area: Rect, buf: &mut Buffer) {
+        let lines = self.combat_log_lines();
+        let visible_lines = usize::from(area.height.saturating_sub(2));
+        self.combat_log_scroll = self
+            .combat_log_scroll
+            .min(lines.len().saturating_sub(visible_lines));
+        let end = lines.len() - self.combat_log_scroll;
+        let start = end.saturating_sub(visible_lines);
+        let filter = match self.combat_log_filter {
+            Some(seat) => seat.to_string(),
+            None => "All".into(),
+        };
+        let block =
+            Block::bordered().title(Line::raw(format!("Combat Log ({})", filter)).centered());
+        Paragraph::new(
+            lines[start..end]
+                .iter()
+                .map(|line| Line::raw(line.as_str()))
+                .collect::<Vec<_>>(),
+        )
+        .block(block)
+        .render(area, buf);
+    }
+
     fn render_available_cards(&mut self, area: Rect, buf: &mut Buffer) {
-        let (active_hand, _active_deck) = self
-            .game_board
-            .active_player_data()
-            .expect("can't get active player data");
+        let active_hand = self.game_board.hand(&self.viewing_seat());
         let num_cards = active_hand.inactive_cards.len() as u32;
         if num_cards == 0 {
             return;
@@ -228,11 +529,15 @@ impl GameApp {
                 .title(Line::raw(format!("Card {}", i + 1)).centered())
                 .style(match (is_selected, is_hovered) {
                     (true, true) => Style::default()
-                        .fg(SLATE.c100)
-                        .bg(SLATE.c800)
+                        .fg(self.theme.highlight_text)
+                        .bg(self.theme.selected_hovered_bg)
                         .add_modifier(Modifier::BOLD), // selected & hovered
-                    (true, false) => Style::default().fg(SLATE.c100).bg(GREEN.c800), // selected
-                    (false, true) => Style::default().fg(SLATE.c200).bg(SLATE.c600), // hovered
+                    (true, false) => Style::default()
+                        .fg(self.theme.highlight_text)
+                        .bg(self.theme.selected_bg), // selected
+                    (false, true) => Style::default()
+                        .fg(self.theme.highlight_text)
+                        .bg(self.theme.hovered_bg), // hovered
                     _ => Style::default(),
                 });
 
@@ -240,31 +545,127 @@ impl GameApp {
                 .inactive_cards
                 .get(i)
                 .expect("couldn't get inactive card from active hand");
-            let card_text = Paragraph::new(format!("{:?}", card))
+            CardWidget::new(&card.card, &self.theme)
                 .block(card_block)
-                .alignment(Alignment::Center)
-                .wrap(Wrap { trim: true });
-
-            card_text.render(*card_area, buf);
+                .render(*card_area, buf);
         }
     }
 }
 impl Widget for &mut GameApp {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Some(seat) = self.handoff_to {
+            self.render_handoff(seat, area, buf);
+            return;
+        }
         let [game_board_area, footer_area] =
-            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(2)]).areas(area);
 
-        let [opponent_philosopher, player_philosopher, player_available_cards] =
-            Layout::vertical([
-                Constraint::Ratio(1, 4),
-                Constraint::Ratio(1, 4),
-                Constraint::Ratio(1, 2),
-            ])
-            .areas(game_board_area);
+        let [philosophers_area, player_available_cards] =
+            Layout::vertical([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                .areas(game_board_area);
+        let [philosopher_panels_area, combat_log_area] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .areas(philosophers_area);
+        let [opponent_philosopher, player_philosopher] =
+            Layout::vertical([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                .areas(philosopher_panels_area);
 
         self.render_footer(footer_area, buf);
         self.render_opponent_philosophers(opponent_philosopher, buf);
         self.render_player_philosophers(player_philosopher, buf);
+        self.render_combat_log(combat_log_area, buf);
         self.render_available_cards(player_available_cards, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_example_board;
+
+    fn get_example_app() -> GameApp {
+        let mut game_board = get_example_board();
+        game_board.process_turn(vec![CardId(0)]).unwrap();
+        GameApp::new(game_board, ControllerKind::Human, ControllerKind::Human)
+    }
+
+    #[test]
+    fn test_combat_log_lines_skip_phase_changes() {
+        let game_app = get_example_app();
+        assert_eq!(game_app.combat_log_lines(), ["1: test_dam dealt 1 to test"]);
+    }
+
+    #[test]
+    fn test_ai_turn_shows_thinking_until_it_plays() {
+        let mut game_app = GameApp::new(
+            get_example_board(),
+            ControllerKind::GreedyAi,
+            ControllerKind::Human,
+        );
+        game_app.show_ai_thinking();
+        assert!(matches!(
+            &game_app.status_message,
+            Some(StatusMessage::Info(message)) if message == "Greedy AI (Player 1) is thinking…"
+        ));
+        game_app.take_ai_turn();
+        assert!(game_app.status_message.is_none());
+    }
+
+    #[test]
+    fn test_combat_log_filter_by_seat() {
+        let mut game_app = get_example_app();
+        game_app.cycle_combat_log_filter();
+        assert_eq!(game_app.combat_log_filter, Some(Seat::Player1));
+        assert!(game_app.combat_log_lines().is_empty());
+        game_app.cycle_combat_log_filter();
+        assert_eq!(game_app.combat_log_lines().len(), 1);
+        game_app.cycle_combat_log_filter();
+        assert_eq!(game_app.combat_log_filter, None);
+    }
+
+    #[test]
+    fn test_hot_seat_turn_hands_off_to_next_player() {
+        let mut game_app = GameApp::new(
+            get_example_board(),
+            ControllerKind::Human,
+            ControllerKind::Human,
+        );
+        game_app.selected_cards[0] = CardSelectionState::Selected;
+        game_app.submit_card_selections().unwrap();
+        assert_eq!(game_app.handoff_to, Some(Seat::Player2));
+        assert_eq!(game_app.handoff_summary(), ["test_dam dealt 1 to test"]);
+
+        game_app.handle_key(event::KeyEvent::from(KeyCode::Enter));
+        assert_eq!(game_app.handoff_to, None);
+        assert_eq!(game_app.game_board.turn_history().len(), 1);
+    }
+
+    #[test]
+    fn test_no_handoff_against_ai() {
+        let mut game_app = GameApp::new(
+            get_example_board(),
+            ControllerKind::Human,
+            ControllerKind::GreedyAi,
+        );
+        game_app.selected_cards[0] = CardSelectionState::Selected;
+        game_app.submit_card_selections().unwrap();
+        assert_eq!(game_app.handoff_to, None);
+    }
+
+    #[test]
+    fn test_finished_game_renders_without_an_active_seat() {
+        let yaml = serde_yaml::to_string(&get_example_board()).unwrap();
+        let game_board: GameBoard = serde_yaml::from_str(&yaml.replacen(
+            "game_phase: Player1Turn",
+            "game_phase: GameOver",
+            1,
+        ))
+        .unwrap();
+        let mut game_app = GameApp::new(game_board, ControllerKind::Human, ControllerKind::Human);
+        game_app.check_for_game_over();
+        assert!(game_app.exit);
+        let area = Rect::new(0, 0, 120, 40);
+        let mut buf = Buffer::empty(area);
+        (&mut game_app).render(area, &mut buf);
+    }
+}
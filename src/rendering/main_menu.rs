@@ -0,0 +1,779 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+    DefaultTerminal,
+};
+
+use crate::controllers::{ControllerKind, Difficulty};
+use crate::game_error::GameError;
+use crate::game_management::{
+    self, available_decklists, available_packs, available_replays, replays_dir, CardSet,
+    ConfigPreset, DeckRules, Decklist, GameBoard, GameConfig, PackManifest, Replay, Seat,
+};
+use crate::rendering::theme::ThemePreset;
+use crate::rendering::{StatusMessage, Theme};
+use std::path::PathBuf;
+
+const MENU_ITEMS: [MenuItem; 7] = [
+    MenuItem::NewGame,
+    MenuItem::Continue,
+    MenuItem::Load,
+    MenuItem::Replays,
+    MenuItem::DeckBuilder,
+    MenuItem::Settings,
+    MenuItem::Quit,
+];
+const CONTROLLERS: [ControllerKind; 6] = [
+    ControllerKind::Human,
+    ControllerKind::RandomAi,
+    ControllerKind::GreedyAi,
+    ControllerKind::MctsAi(Difficulty::Easy),
+    ControllerKind::MctsAi(Difficulty::Medium),
+    ControllerKind::MctsAi(Difficulty::Hard),
+];
+const CONFIG_PRESETS: [ConfigPreset; 3] = [
+    ConfigPreset::Standard,
+    ConfigPreset::Quick,
+    ConfigPreset::Endurance,
+];
+const THEME_PRESETS: [ThemePreset; 3] = [
+    ThemePreset::Dark,
+    ThemePreset::Light,
+    ThemePreset::HighContrast,
+];
+// both players, both decks and the rules come before one row per card pack
+const FIRST_PACK_ROW: usize = 5;
+// the deck choice before any decklists, which plays one copy of every card
+const STANDARD_DECK: &str = "Standard";
+const NEW_DECK: &str = "New Deck";
+const OTHER_REPLAY: &str = "Open another file...";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MenuItem {
+    NewGame,
+    Continue,
+    Load,
+    Replays,
+    DeckBuilder,
+    Settings,
+    Quit,
+}
+impl std::fmt::Display for MenuItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MenuItem::NewGame => write!(f, "New Game"),
+            MenuItem::Continue => write!(f, "Continue"),
+            MenuItem::Load => write!(f, "Load"),
+            MenuItem::Replays => write!(f, "Replays"),
+            MenuItem::DeckBuilder => write!(f, "Deck Builder"),
+            MenuItem::Settings => write!(f, "Settings"),
+            MenuItem::Quit => write!(f, "Quit"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PathPurpose {
+    LoadGame,
+    ViewReplay,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Screen {
+    Main,
+    Setup,
+    PathPrompt { purpose: PathPurpose, path: String },
+    Settings,
+    Decks,
+    Replays,
+}
+
+pub enum MenuAction {
+    StartGame {
+        game_board: Box<GameBoard>,
+        player_1: ControllerKind,
+        player_2: ControllerKind,
+    },
+    ViewReplay(Replay),
+    BuildDeck {
+        card_set: CardSet,
+        rules: DeckRules,
+        decklist: Decklist,
+        // the file the decklist was loaded from, none for a new deck
+        path: Option<PathBuf>,
+    },
+    Quit,
+}
+
+// the option after (or before) `current`, wrapping around at either end
+pub(super) fn cycle<T: Copy + PartialEq>(options: &[T], current: T, forward: bool) -> T {
+    let i = options.iter().position(|o| *o == current).unwrap_or(0);
+    let next = if forward {
+        (i + 1) % options.len()
+    } else {
+        (i + options.len() - 1) % options.len()
+    };
+    options[next]
+}
+
+pub struct MainMenu {
+    screen: Screen,
+    selected_row: usize,
+    player_1: ControllerKind,
+    player_2: ControllerKind,
+    decklists: Vec<(PathBuf, Decklist)>,
+    deck_rules: DeckRules,
+    // newest first
+    replays: Vec<PathBuf>,
+    // 0 is the standard deck, anything else is one past its index in `decklists`
+    player_1_deck: usize,
+    player_2_deck: usize,
+    config_preset: ConfigPreset,
+    packs: Vec<(PackManifest, bool)>,
+    theme_name: String,
+    theme: Theme,
+    status_message: Option<StatusMessage>,
+}
+impl MainMenu {
+    pub fn new(theme_name: String, theme: Theme) -> Self {
+        let mut status_message = None;
+        let packs = match available_packs() {
+            Ok(available) => {
+                // the packs that did load stay usable alongside the error
+                if let Some((folder, e)) = available.broken.first() {
+                    status_message = Some(StatusMessage::Error(format!(
+                        "skipped {} broken card pack(s), first '{}': {}",
+                        available.broken.len(),
+                        folder,
+                        e
+                    )));
+                }
+                available
+                    .packs
+                    .into_iter()
+                    .map(|pack| (pack.manifest, false))
+                    .collect()
+            }
+            Err(e) => {
+                status_message = Some(StatusMessage::Error(e.to_string()));
+                vec![]
+            }
+        };
+        let decklists = match available_decklists() {
+            Ok(decklists) => decklists,
+            Err(e) => {
+                status_message = Some(StatusMessage::Error(e.to_string()));
+                vec![]
+            }
+        };
+        let deck_rules = match DeckRules::load() {
+            Ok(deck_rules) => deck_rules,
+            Err(e) => {
+                status_message = Some(StatusMessage::Error(e.to_string()));
+                DeckRules::default()
+            }
+        };
+        MainMenu {
+            screen: Screen::Main,
+            selected_row: 0,
+            player_1: ControllerKind::Human,
+            player_2: ControllerKind::Human,
+            decklists,
+            deck_rules,
+            replays: vec![],
+            player_1_deck: 0,
+            player_2_deck: 0,
+            config_preset: ConfigPreset::Standard,
+            packs,
+            theme_name,
+            theme,
+            status_message,
+        }
+    }
+
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    pub fn show_message(&mut self, message: StatusMessage) {
+        self.status_message = Some(message);
+    }
+
+    /// Shows the menu until the player picks something that needs another screen.
+    pub fn run(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+    ) -> Result<MenuAction, Box<dyn std::error::Error>> {
+        loop {
+            terminal.draw(|frame| frame.render_widget(&mut *self, frame.area()))?;
+            if let Event::Key(key) = event::read()? {
+                if let Some(action) = self.handle_key(key) {
+                    return Ok(action);
+                }
+            }
+        }
+    }
+
+    fn open(&mut self, screen: Screen) {
+        self.screen = screen;
+        self.selected_row = 0;
+    }
+
+    // the last setup row is the start button
+    fn start_row(&self) -> usize {
+        FIRST_PACK_ROW + self.packs.len()
+    }
+
+    fn num_rows(&self) -> usize {
+        match self.screen {
+            Screen::Main => MENU_ITEMS.len(),
+            Screen::Setup => self.start_row() + 1,
+            Screen::PathPrompt { .. } | Screen::Settings => 1,
+            // a new deck, then every saved decklist
+            Screen::Decks => self.decklists.len() + 1,
+            // every saved replay, then a prompt for any other file
+            Screen::Replays => self.replays.len() + 1,
+        }
+    }
+
+    fn handle_key(&mut self, key: event::KeyEvent) -> Option<MenuAction> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        self.status_message = None;
+        if let Screen::PathPrompt { purpose, path } = &mut self.screen {
+            match key.code {
+                KeyCode::Esc => self.open(Screen::Main),
+                KeyCode::Backspace => {
+                    path.pop();
+                }
+                KeyCode::Char(c) => path.push(c),
+                KeyCode::Enter => {
+                    let (purpose, path) = (*purpose, path.clone());
+                    return self.report_error(self.load_path(purpose, &path));
+                }
+                _ => {}
+            }
+            return None;
+        }
+        match key.code {
+            KeyCode::Char('q') if self.screen == Screen::Main => return Some(MenuAction::Quit),
+            KeyCode::Char('q') | KeyCode::Esc => self.open(Screen::Main),
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.selected_row = (self.selected_row + self.num_rows() - 1) % self.num_rows();
+            }
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.selected_row = (self.selected_row + 1) % self.num_rows();
+            }
+            KeyCode::Left | KeyCode::Char('a') => self.change_option(false),
+            KeyCode::Right | KeyCode::Char('d') => self.change_option(true),
+            KeyCode::Enter => return self.activate(),
+            _ => {}
+        }
+        None
+    }
+
+    fn report_error(&mut self, result: Result<MenuAction, GameError>) -> Option<MenuAction> {
+        match result {
+            Ok(action) => Some(action),
+            Err(e) => {
+                self.status_message = Some(StatusMessage::Error(e.to_string()));
+                None
+            }
+        }
+    }
+
+    fn activate(&mut self) -> Option<MenuAction> {
+        match self.screen {
+            Screen::Main => match MENU_ITEMS[self.selected_row] {
+                MenuItem::NewGame => self.open(Screen::Setup),
+                MenuItem::Continue => {
+                    let path = game_management::save_path().to_string_lossy().into_owned();
+                    return self.report_error(self.load_path(PathPurpose::LoadGame, &path));
+                }
+                MenuItem::Load => self.open(Screen::PathPrompt {
+                    purpose: PathPurpose::LoadGame,
+                    path: game_management::save_path().to_string_lossy().into_owned(),
+                }),
+                MenuItem::Replays => {
+                    self.reload_replays();
+                    self.open(Screen::Replays);
+                }
+                MenuItem::DeckBuilder => self.open(Screen::Decks),
+                MenuItem::Settings => self.open(Screen::Settings),
+                MenuItem::Quit => return Some(MenuAction::Quit),
+            },
+            Screen::Setup if self.selected_row == self.start_row() => {
+                return self.report_error(self.start_game())
+            }
+            Screen::Setup | Screen::Settings => self.change_option(true),
+            Screen::Decks => return self.report_error(self.build_deck()),
+            Screen::Replays => match self.replays.get(self.selected_row) {
+                Some(path) => {
+                    let path = path.to_string_lossy().into_owned();
+                    return self.report_error(self.load_path(PathPurpose::ViewReplay, &path));
+                }
+                None => self.open(Screen::PathPrompt {
+                    purpose: PathPurpose::ViewReplay,
+                    path: replays_dir().join("").to_string_lossy().into_owned(),
+                }),
+            },
+            Screen::PathPrompt { .. } => {}
+        }
+        None
+    }
+
+    fn change_option(&mut self, forward: bool) {
+        match (&self.screen, self.selected_row) {
+            (Screen::Setup, 0) => self.player_1 = cycle(&CONTROLLERS, self.player_1, forward),
+            (Screen::Setup, 1) => self.player_2 = cycle(&CONTROLLERS, self.player_2, forward),
+            (Screen::Setup, 2) => {
+                self.player_1_deck = cycle(&self.deck_indices(), self.player_1_deck, forward)
+            }
+            (Screen::Setup, 3) => {
+                self.player_2_deck = cycle(&self.deck_indices(), self.player_2_deck, forward)
+            }
+            (Screen::Setup, 4) => {
+                self.config_preset = cycle(&CONFIG_PRESETS, self.config_preset, forward)
+            }
+            (Screen::Setup, row) if row >= FIRST_PACK_ROW && row < self.start_row() => {
+                let (_, enabled) = &mut self.packs[row - FIRST_PACK_ROW];
+                *enabled = !*enabled;
+            }
+            (Screen::Settings, 0) => {
+                let current = self.theme_name.parse().unwrap_or(ThemePreset::Dark);
+                let preset = cycle(&THEME_PRESETS, current, forward);
+                match Theme::from_preset(preset) {
+                    Ok(theme) => {
+                        self.theme = theme;
+                        self.theme_name = preset.to_string();
+                    }
+                    Err(e) => self.status_message = Some(StatusMessage::Error(e.to_string())),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn enabled_packs(&self) -> Vec<String> {
+        self.packs
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(manifest, _)| manifest.name.clone())
+            .collect()
+    }
+
+    fn deck_indices(&self) -> Vec<usize> {
+        (0..=self.decklists.len()).collect()
+    }
+
+    fn decklist(&self, deck: usize) -> Option<&Decklist> {
+        deck.checked_sub(1).map(|i| &self.decklists[i].1)
+    }
+
+    fn deck_name(&self, deck: usize) -> String {
+        self.decklist(deck)
+            .map_or(STANDARD_DECK.into(), |decklist| decklist.name.clone())
+    }
+
+    fn start_game(&self) -> Result<MenuAction, GameError> {
+        let game_config = GameConfig::from_preset(self.config_preset)
+            .with_packs(self.enabled_packs())
+            .with_deck_rules(self.deck_rules.clone())
+            .with_decklist(Seat::Player1, self.decklist(self.player_1_deck).cloned())
+            .with_decklist(Seat::Player2, self.decklist(self.player_2_deck).cloned());
+        let game_board = GameBoard::new(Some(game_config))?;
+        Ok(MenuAction::StartGame {
+            game_board: Box::new(game_board),
+            player_1: self.player_1,
+            player_2: self.player_2,
+        })
+    }
+
+    // decks are built from the packs currently picked in game setup
+    fn build_deck(&self) -> Result<MenuAction, GameError> {
+        let (path, decklist) = match self.selected_row.checked_sub(1) {
+            Some(i) => {
+                let (path, decklist) = self.decklists[i].clone();
+                (Some(path), decklist)
+            }
+            None => (None, Decklist::new(NEW_DECK.into())),
+        };
+        Ok(MenuAction::BuildDeck {
+            card_set: CardSet::with_enabled_packs(&self.enabled_packs())?,
+            rules: self.deck_rules.clone(),
+            decklist,
+            path,
+        })
+    }
+
+    /// Picks up decklists saved since the menu was opened.
+    pub fn reload_decklists(&mut self) {
+        match available_decklists() {
+            Ok(decklists) => self.decklists = decklists,
+            Err(e) => self.status_message = Some(StatusMessage::Error(e.to_string())),
+        }
+        if self.player_1_deck > self.decklists.len() {
+            self.player_1_deck = 0;
+        }
+        if self.player_2_deck > self.decklists.len() {
+            self.player_2_deck = 0;
+        }
+    }
+
+    fn reload_replays(&mut self) {
+        match available_replays() {
+            Ok(replays) => self.replays = replays,
+            Err(e) => self.status_message = Some(StatusMessage::Error(e.to_string())),
+        }
+    }
+
+    fn load_path(&self, purpose: PathPurpose, path: &str) -> Result<MenuAction, GameError> {
+        match purpose {
+            PathPurpose::LoadGame => Ok(MenuAction::StartGame {
+                game_board: Box::new(game_management::load_game(path)?),
+                player_1: self.player_1,
+                player_2: self.player_2,
+            }),
+            PathPurpose::ViewReplay => Ok(MenuAction::ViewReplay(Replay::load(path)?)),
+        }
+    }
+}
+
+impl MainMenu {
+    fn row_style(&self, row: usize) -> Style {
+        if row == self.selected_row {
+            Style::default()
+                .fg(self.theme.highlight_text)
+                .bg(self.theme.hovered_bg)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        }
+    }
+
+    fn option_line(&self, row: usize, label: &str, value: String) -> Line<'static> {
+        Line::styled(format!("{:<10} ◀ {} ▶", label, value), self.row_style(row))
+    }
+
+    fn screen_lines(&self) -> (&'static str, Vec<Line<'static>>) {
+        match &self.screen {
+            Screen::Main => (
+                "Infinite Debate",
+                MENU_ITEMS
+                    .iter()
+                    .enumerate()
+                    .map(|(row, item)| Line::styled(item.to_string(), self.row_style(row)))
+                    .collect(),
+            ),
+            Screen::Setup => {
+                let game_config = GameConfig::from_preset(self.config_preset);
+                let mut lines = vec![
+                    self.option_line(0, "Player 1", self.player_1.to_string()),
+                    self.option_line(1, "Player 2", self.player_2.to_string()),
+                    self.option_line(2, "P1 Deck", self.deck_name(self.player_1_deck)),
+                    self.option_line(3, "P2 Deck", self.deck_name(self.player_2_deck)),
+                    self.option_line(4, "Rules", self.config_preset.to_string()),
+                    Line::styled(
+                        format!(
+                            "play up to {}, draw {}, hand of {}, out of cards: {}",
+                            game_config.max_cards_played_per_turn(),
+                            game_config.cards_drawn_per_turn(),
+                            game_config.max_cards_in_hand(),
+                            game_config.out_of_cards_rule()
+                        ),
+                        Style::default().fg(self.theme.muted),
+                    ),
+                ];
+                for (i, (manifest, enabled)) in self.packs.iter().enumerate() {
+                    let state = if *enabled { "on" } else { "off" };
+                    let value = if manifest.description.is_empty() {
+                        format!("{}: {}", manifest.name, state)
+                    } else {
+                        format!("{} ({}): {}", manifest.name, manifest.description, state)
+                    };
+                    lines.push(self.option_line(FIRST_PACK_ROW + i, "Pack", value));
+                }
+                lines.push(Line::raw(""));
+                lines.push(Line::styled("Start Game", self.row_style(self.start_row())));
+                ("New Game", lines)
+            }
+            Screen::PathPrompt { purpose, path } => (
+                match purpose {
+                    PathPurpose::LoadGame => "Load Game",
+                    PathPurpose::ViewReplay => "Replays",
+                },
+                vec![
+                    Line::styled("File to open:", Style::default().fg(self.theme.muted)),
+                    Line::styled(format!("{}▏", path), self.row_style(0)),
+                ],
+            ),
+            Screen::Settings => (
+                "Settings",
+                vec![self.option_line(0, "Theme", self.theme_name.clone())],
+            ),
+            Screen::Decks => (
+                "Deck Builder",
+                (0..self.num_rows())
+                    .map(|row| {
+                        let name = match self.decklist(row) {
+                            Some(decklist) => decklist.name.clone(),
+                            None => NEW_DECK.into(),
+                        };
+                        Line::styled(name, self.row_style(row))
+                    })
+                    .collect(),
+            ),
+            Screen::Replays => (
+                "Replays",
+                (0..self.num_rows())
+                    .map(|row| {
+                        let name = match self.replays.get(row) {
+                            Some(path) => path
+                                .file_stem()
+                                .unwrap_or_default()
+                                .to_string_lossy()
+                                .into_owned(),
+                            None => OTHER_REPLAY.into(),
+                        };
+                        Line::styled(name, self.row_style(row))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn controls(&self) -> &'static str {
+        match self.screen {
+            Screen::Main => "Use ↓↑ to move, [Enter] to select, [q] to quit",
+            Screen::Setup => {
+                "Use ↓↑ to move, ←→ to change, [Enter] on Start Game to play, [Esc] to go back"
+            }
+            Screen::PathPrompt { .. } => "Type a path, [Enter] to open, [Esc] to go back",
+            Screen::Settings => "Use ←→ to change, [Esc] to go back",
+            Screen::Decks => "Use ↓↑ to move, [Enter] to edit, [Esc] to go back",
+            Screen::Replays => "Use ↓↑ to move, [Enter] to watch, [Esc] to go back",
+        }
+    }
+}
+impl Widget for &mut MainMenu {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (title, lines) = self.screen_lines();
+        let [_, menu_area, _, status_area, controls_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(lines.len() as u16 + 2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+        let [_, menu_area, _] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(60),
+            Constraint::Fill(1),
+        ])
+        .areas(menu_area);
+
+        Paragraph::new(lines)
+            .centered()
+            .block(Block::bordered().title(Line::raw(title).centered()))
+            .render(menu_area, buf);
+        match &self.status_message {
+            Some(StatusMessage::Error(message)) => Paragraph::new(message.as_str())
+                .style(Style::default().fg(self.theme.error))
+                .centered()
+                .render(status_area, buf),
+            Some(StatusMessage::Info(message)) => Paragraph::new(message.as_str())
+                .style(Style::default().fg(self.theme.info))
+                .centered()
+                .render(status_area, buf),
+            None => {}
+        }
+        Paragraph::new(self.controls())
+            .centered()
+            .render(controls_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir;
+
+    fn press(main_menu: &mut MainMenu, code: KeyCode) -> Option<MenuAction> {
+        main_menu.handle_key(event::KeyEvent::from(code))
+    }
+
+    fn get_example_menu() -> MainMenu {
+        MainMenu::new("dark".into(), Theme::default())
+    }
+
+    #[test]
+    fn test_cycle_wraps_around() {
+        assert_eq!(cycle(&[1, 2, 3], 3, true), 1);
+        assert_eq!(cycle(&[1, 2, 3], 1, false), 3);
+    }
+
+    #[test]
+    fn test_setup_starts_game_with_chosen_controllers() {
+        let mut main_menu = get_example_menu();
+        press(&mut main_menu, KeyCode::Enter);
+        assert_eq!(main_menu.screen, Screen::Setup);
+        press(&mut main_menu, KeyCode::Down);
+        press(&mut main_menu, KeyCode::Right);
+        press(&mut main_menu, KeyCode::Down);
+        press(&mut main_menu, KeyCode::Down);
+        press(&mut main_menu, KeyCode::Down);
+        press(&mut main_menu, KeyCode::Left);
+        press(&mut main_menu, KeyCode::Down);
+        match press(&mut main_menu, KeyCode::Enter) {
+            Some(MenuAction::StartGame {
+                game_board,
+                player_1,
+                player_2,
+            }) => {
+                assert_eq!(player_1, ControllerKind::Human);
+                assert_eq!(player_2, ControllerKind::RandomAi);
+                assert_eq!(
+                    game_board.game_config.out_of_cards_rule(),
+                    GameConfig::from_preset(ConfigPreset::Endurance).out_of_cards_rule()
+                );
+            }
+            _ => panic!("expected the game to start"),
+        }
+    }
+
+    #[test]
+    fn test_setup_picks_a_deck_per_seat() {
+        let mut main_menu = get_example_menu();
+        main_menu.decklists = vec![(
+            "too_small.yaml".into(),
+            Decklist {
+                name: "Too Small".into(),
+                cards: vec![],
+            },
+        )];
+        main_menu.open(Screen::Setup);
+        main_menu.selected_row = 3;
+        press(&mut main_menu, KeyCode::Right);
+        assert_eq!(main_menu.deck_name(main_menu.player_1_deck), "Standard");
+        assert_eq!(main_menu.deck_name(main_menu.player_2_deck), "Too Small");
+        main_menu.selected_row = main_menu.start_row();
+        assert!(press(&mut main_menu, KeyCode::Enter).is_none());
+        assert!(matches!(
+            main_menu.status_message,
+            Some(StatusMessage::Error(ref message)) if message.contains("'Too Small'")
+        ));
+    }
+
+    #[test]
+    fn test_deck_builder_edits_new_or_saved_deck() {
+        let mut main_menu = get_example_menu();
+        main_menu.decklists = vec![("saved.yaml".into(), Decklist::new("Saved".into()))];
+        main_menu.selected_row = MENU_ITEMS
+            .iter()
+            .position(|item| *item == MenuItem::DeckBuilder)
+            .unwrap();
+        press(&mut main_menu, KeyCode::Enter);
+        assert_eq!(main_menu.screen, Screen::Decks);
+        for (row, name, loaded_from) in [(0, NEW_DECK, None), (1, "Saved", Some("saved.yaml"))] {
+            main_menu.selected_row = row;
+            match press(&mut main_menu, KeyCode::Enter) {
+                Some(MenuAction::BuildDeck { decklist, path, .. }) => {
+                    assert_eq!(decklist.name, name);
+                    assert_eq!(path, loaded_from.map(PathBuf::from));
+                }
+                _ => panic!("expected the deck builder to open"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_missing_file_shows_error() {
+        let mut main_menu = get_example_menu();
+        main_menu.open(Screen::PathPrompt {
+            purpose: PathPurpose::LoadGame,
+            path: "./missing_save".into(),
+        });
+        press(&mut main_menu, KeyCode::Char('x'));
+        assert!(press(&mut main_menu, KeyCode::Enter).is_none());
+        assert!(matches!(
+            main_menu.status_message,
+            Some(StatusMessage::Error(ref message)) if message.contains("./missing_savex")
+        ));
+    }
+
+    #[test]
+    fn test_settings_cycles_theme() {
+        let mut main_menu = get_example_menu();
+        main_menu.open(Screen::Settings);
+        press(&mut main_menu, KeyCode::Right);
+        assert_eq!(main_menu.theme_name, "light");
+        assert_eq!(
+            main_menu.theme(),
+            &Theme::from_preset(ThemePreset::Light).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_replays_screen_lists_saved_replays() {
+        let mut main_menu = get_example_menu();
+        let game_board = GameBoard::new(Some(GameConfig::default().with_seed(7))).unwrap();
+        let path = get_temp_dir("replay").with_extension("yaml");
+        Replay::from_board(&game_board)
+            .unwrap()
+            .save(&path.to_string_lossy())
+            .unwrap();
+        main_menu.open(Screen::Replays);
+        main_menu.replays = vec![path.clone()];
+        let action = press(&mut main_menu, KeyCode::Enter);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(action, Some(MenuAction::ViewReplay(replay)) if replay.seed() == 7));
+
+        press(&mut main_menu, KeyCode::Down);
+        assert!(press(&mut main_menu, KeyCode::Enter).is_none());
+        assert!(matches!(
+            main_menu.screen,
+            Screen::PathPrompt {
+                purpose: PathPurpose::ViewReplay,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_quit() {
+        let mut main_menu = get_example_menu();
+        assert!(matches!(
+            press(&mut main_menu, KeyCode::Char('q')),
+            Some(MenuAction::Quit)
+        ));
+    }
+
+    #[test]
+    fn test_every_screen_renders() {
+        let mut main_menu = get_example_menu();
+        for screen in [
+            Screen::Main,
+            Screen::Setup,
+            Screen::PathPrompt {
+                purpose: PathPurpose::ViewReplay,
+                path: "replays/".into(),
+            },
+            Screen::Settings,
+            Screen::Decks,
+            Screen::Replays,
+        ] {
+            main_menu.open(screen);
+            let area = Rect::new(0, 0, 80, 24);
+            let mut buf = Buffer::empty(area);
+            (&mut main_menu).render(area, &mut buf);
+            let rendered: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+            assert!(rendered.contains(main_menu.screen_lines().0));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, Paragraph, Widget, Wrap},
+    DefaultTerminal,
+};
+
+use crate::game_error::GameError;
+use crate::game_management::{GameBoard, GameEvent, Replay, Seat};
+use crate::rendering::Theme;
+
+pub struct ReplayViewer {
+    exit: bool,
+    replay: Replay,
+    current_turn: usize,
+    game_board: GameBoard,
+    last_turn: Option<(Seat, Vec<String>)>,
+    error_message: Option<String>,
+    theme: Theme,
+}
+impl ReplayViewer {
+    pub fn new(replay: Replay) -> Result<Self, GameError> {
+        let game_board = replay.board_after(0)?;
+        Ok(ReplayViewer {
+            exit: false,
+            replay,
+            current_turn: 0,
+            game_board,
+            last_turn: None,
+            error_message: None,
+            theme: Theme::default(),
+        })
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<(), Box<dyn std::error::Error>> {
+        while !self.exit {
+            terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            };
+        }
+        Ok(())
+    }
+
+    fn handle_key(&mut self, key: event::KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        let num_turns = self.replay.num_turns();
+        let result = match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.exit = true;
+                Ok(())
+            }
+            KeyCode::Right | KeyCode::Char('d') if self.current_turn < num_turns => {
+                self.step_to(self.current_turn + 1)
+            }
+            KeyCode::Left | KeyCode::Char('a') if self.current_turn > 0 => {
+                self.step_to(self.current_turn - 1)
+            }
+            KeyCode::Home => self.step_to(0),
+            KeyCode::End => self.step_to(num_turns),
+            _ => Ok(()),
+        };
+        self.error_message = result.err().map(|e| e.to_string());
+    }
+
+    // rebuilds the board from the start of the game rather than undoing turns
+    fn step_to(&mut self, turn: usize) -> Result<(), GameError> {
+        let mut game_board = self.replay.board_after(turn.saturating_sub(1))?;
+        self.last_turn = None;
+        if let (Some(card_ids), Some(seat)) = (
+            turn.checked_sub(1).and_then(|i| self.replay.turn(i)),
+            game_board.game_phase().active_seat(),
+        ) {
+            let hand = game_board.hand(&seat);
+            let played_cards = card_ids
+                .iter()
+                .filter_map(|card_id| hand.get_card(card_id))
+                .map(|card| card.card.name().to_string())
+                .collect();
+            game_board.process_turn(card_ids.to_vec())?;
+            self.last_turn = Some((seat, played_cards));
+        }
+        self.game_board = game_board;
+        self.current_turn = turn;
+        Ok(())
+    }
+}
+
+impl ReplayViewer {
+    fn render_seat(&self, seat: Seat, area: Rect, buf: &mut Buffer) {
+        let hand = self.game_board.hand(&seat);
+        let active_philosopher = match &hand.active_philosopher {
+            Some(p) => format!(
+                "{} ({:?}) - {} health",
+                p.philosopher.name,
+                p.philosopher.school,
+                p.remaining_health()
+            ),
+            None => "No philosopher in play".into(),
+        };
+        let hand_cards: Vec<&str> = hand
+            .inactive_cards
+            .iter()
+            .map(|card| card.card.name())
+            .collect();
+        let block = Block::bordered().title(Line::raw(seat.to_string()).centered());
+        Paragraph::new(vec![
+            Line::raw(active_philosopher),
+            Line::raw(format!("Hand: {}", hand_cards.join(", "))),
+            Line::raw(format!(
+                "Deck: {} | Discard: {} | Graveyard: {}",
+                self.game_board.remaining_deck(&seat).num_remaining_cards(),
+                self.game_board.discard_pile(&seat).num_cards(),
+                self.game_board.graveyard(&seat).num_cards()
+            )),
+        ])
+        .centered()
+        .wrap(Wrap { trim: true })
+        .block(block)
+        .render(area, buf);
+    }
+
+    fn render_turn_summary(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered().title(
+            Line::raw(format!(
+                "Replay (seed {}) - turn {} of {}",
+                self.replay.seed(),
+                self.current_turn,
+                self.replay.num_turns()
+            ))
+            .centered(),
+        );
+        let mut lines = vec![match &self.last_turn {
+            Some((seat, played_cards)) if played_cards.is_empty() => {
+                Line::raw(format!("{} passed", seat))
+            }
+            Some((seat, played_cards)) => {
+                Line::raw(format!("{} played {}", seat, played_cards.join(", ")))
+            }
+            None => Line::raw("Start of game"),
+        }];
+        if let Some(reason) = self.game_board.game_over_reason() {
+            lines.push(Line::raw(format!("Game Over: {}", reason)));
+        }
+        lines.extend(
+            self.game_board
+                .event_log()
+                .for_turn(self.current_turn.saturating_sub(1))
+                .filter(|entry| !matches!(entry.event, GameEvent::PhaseChanged { .. }))
+                .map(|entry| Line::raw(entry.event.to_string())),
+        );
+        Paragraph::new(lines)
+            .centered()
+            .block(block)
+            .render(area, buf);
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut Buffer) {
+        match &self.error_message {
+            Some(message) => Paragraph::new(format!("Can't replay: {}", message))
+                .style(Style::default().fg(self.theme.error))
+                .centered()
+                .render(area, buf),
+            None => Paragraph::new(
+                "Use ←→ to step through turns, [Home]/[End] to jump to the start/end, [q] to quit",
+            )
+            .centered()
+            .render(area, buf),
+        }
+    }
+}
+impl Widget for &mut ReplayViewer {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [summary_area, player_2_area, player_1_area, footer_area] = Layout::vertical([
+            Constraint::Length(7),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        self.render_turn_summary(summary_area, buf);
+        self.render_seat(Seat::Player2, player_2_area, buf);
+        self.render_seat(Seat::Player1, player_1_area, buf);
+        self.render_footer(footer_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_management::GameConfig;
+
+    fn get_example_viewer() -> ReplayViewer {
+        let mut game_board = GameBoard::new(Some(GameConfig::default().with_seed(5))).unwrap();
+        for _ in 0..4 {
+            let Some(turn) = game_board.legal_turns().pop() else {
+                break;
+            };
+            game_board.process_turn(turn).unwrap();
+        }
+        ReplayViewer::new(Replay::from_board(&game_board).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_step_forward_and_back_matches_replay() {
+        let mut viewer = get_example_viewer();
+        viewer.step_to(2).unwrap();
+        viewer.step_to(1).unwrap();
+        assert_eq!(
+            format!("{:?}", viewer.game_board),
+            format!("{:?}", viewer.replay.board_after(1).unwrap())
+        );
+        assert_eq!(
+            viewer.last_turn.as_ref().map(|(seat, _)| *seat),
+            Some(Seat::Player1)
+        );
+    }
+
+    #[test]
+    fn test_step_to_start_clears_last_turn() {
+        let mut viewer = get_example_viewer();
+        viewer.step_to(3).unwrap();
+        viewer.step_to(0).unwrap();
+        assert!(viewer.last_turn.is_none());
+        assert!(viewer.game_board.turn_history().is_empty());
+    }
+}
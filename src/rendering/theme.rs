@@ -0,0 +1,150 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::str::FromStr;
+
+use crate::entities::CoreSchool;
+use crate::game_error::GameError;
+use crate::game_management::{load_asset, load_yaml};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThemePreset {
+    Dark,
+    Light,
+    HighContrast,
+}
+impl ThemePreset {
+    fn file_name(&self) -> &'static str {
+        match self {
+            ThemePreset::Dark => "themes/dark.yaml",
+            ThemePreset::Light => "themes/light.yaml",
+            ThemePreset::HighContrast => "themes/high_contrast.yaml",
+        }
+    }
+}
+impl std::fmt::Display for ThemePreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemePreset::Dark => write!(f, "dark"),
+            ThemePreset::Light => write!(f, "light"),
+            ThemePreset::HighContrast => write!(f, "high-contrast"),
+        }
+    }
+}
+impl FromStr for ThemePreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dark" => Ok(ThemePreset::Dark),
+            "light" => Ok(ThemePreset::Light),
+            "high-contrast" => Ok(ThemePreset::HighContrast),
+            _ => Err(format!(
+                "unknown theme '{}', expected dark, light or high-contrast",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct SchoolColors {
+    pub rationalist: Color,
+    pub empiricist: Color,
+    pub skeptic: Color,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Theme {
+    pub muted: Color,
+    pub highlight_text: Color,
+    pub hovered_bg: Color,
+    pub selected_bg: Color,
+    pub selected_hovered_bg: Color,
+    pub error: Color,
+    pub info: Color,
+    pub health_high: Color,
+    pub health_low: Color,
+    pub poison: Color,
+    pub recovery: Color,
+    pub schools: SchoolColors,
+}
+impl Theme {
+    pub fn from_preset(preset: ThemePreset) -> Result<Self, GameError> {
+        load_asset(preset.file_name())
+    }
+
+    /// Loads a preset by name, or any other value as the path to a theme file.
+    pub fn load(name_or_path: &str) -> Result<Self, GameError> {
+        match ThemePreset::from_str(name_or_path) {
+            Ok(preset) => Theme::from_preset(preset),
+            Err(_) => load_yaml(name_or_path),
+        }
+    }
+
+    pub fn school(&self, school: &CoreSchool) -> Color {
+        match school {
+            CoreSchool::Rationalist => self.schools.rationalist,
+            CoreSchool::Empiricist => self.schools.empiricist,
+            CoreSchool::Skeptic => self.schools.skeptic,
+        }
+    }
+}
+// matches the dark preset
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            muted: Color::from_u32(0x94a3b8),
+            highlight_text: Color::from_u32(0xf1f5f9),
+            hovered_bg: Color::from_u32(0x475569),
+            selected_bg: Color::from_u32(0x166534),
+            selected_hovered_bg: Color::from_u32(0x1e293b),
+            error: Color::from_u32(0xf87171),
+            info: Color::from_u32(0x4ade80),
+            health_high: Color::from_u32(0x16a34a),
+            health_low: Color::from_u32(0xdc2626),
+            poison: Color::from_u32(0xf87171),
+            recovery: Color::from_u32(0x4ade80),
+            schools: SchoolColors {
+                rationalist: Color::from_u32(0x60a5fa),
+                empiricist: Color::from_u32(0xfbbf24),
+                skeptic: Color::from_u32(0xc084fc),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_preset_loads() {
+        for preset in [
+            ThemePreset::Dark,
+            ThemePreset::Light,
+            ThemePreset::HighContrast,
+        ] {
+            assert!(Theme::from_preset(preset).is_ok(), "{} failed", preset);
+        }
+    }
+
+    #[test]
+    fn test_default_theme_matches_dark_preset() {
+        assert_eq!(
+            Theme::from_preset(ThemePreset::Dark).unwrap(),
+            Theme::default()
+        );
+    }
+
+    #[test]
+    fn test_load_by_name_or_path() {
+        assert_eq!(
+            Theme::load("high-contrast").unwrap(),
+            Theme::load("./assets/themes/high_contrast.yaml").unwrap()
+        );
+        assert!(matches!(
+            Theme::load("./assets/themes/missing.yaml"),
+            Err(GameError::AssetLoad { .. })
+        ));
+    }
+}
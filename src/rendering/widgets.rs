@@ -0,0 +1,327 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Gauge, Paragraph, Widget, Wrap},
+};
+
+use crate::entities::{
+    AbilityType, Action, ActiveEffect, Card, CoreSchool, Effect, EffectTarget, InPlayPhilosopher,
+    Philosopher,
+};
+use crate::rendering::Theme;
+
+fn turns(duration: u8) -> String {
+    match duration {
+        1 => "1 turn".into(),
+        _ => format!("{} turns", duration),
+    }
+}
+
+fn effect_text(effect: &Effect) -> String {
+    match effect {
+        Effect::Poison { damage, duration } => {
+            format!("Poison {} for {}", damage, turns(*duration))
+        }
+        Effect::Recovery { heal, duration } => {
+            format!("Recovery {} for {}", heal, turns(*duration))
+        }
+    }
+}
+
+fn active_effect_line(active_effect: &ActiveEffect, theme: &Theme) -> Line<'static> {
+    let (kind, color) = match active_effect.effect {
+        Effect::Poison { .. } => ("Poison", theme.poison),
+        Effect::Recovery { .. } => ("Recovery", theme.recovery),
+    };
+    Line::styled(
+        format!(
+            "{} {} ({} left, from {})",
+            kind,
+            active_effect.effect.magnitude(),
+            turns(active_effect.effect.duration()),
+            active_effect.source
+        ),
+        Style::default().fg(color),
+    )
+}
+
+fn title_lines(name: &str, school: &CoreSchool, theme: &Theme) -> Vec<Line<'static>> {
+    vec![
+        Line::styled(
+            name.to_string(),
+            Style::default()
+                .fg(theme.school(school))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::styled(format!("{:?}", school), Style::default().fg(theme.muted)),
+    ]
+}
+
+fn action_lines(action: &Action, theme: &Theme) -> Vec<Line<'static>> {
+    let (ability, duration) = match action.ability_type {
+        AbilityType::Damage { damage, duration } => (format!("Damage {}", damage), duration),
+        AbilityType::Heal { heal, duration } => (format!("Heal {}", heal), duration),
+    };
+    let mut lines = title_lines(action.name(), action.school(), theme);
+    lines.push(Line::raw(""));
+    lines.push(Line::raw(action.description().to_string()));
+    lines.push(Line::raw(""));
+    lines.push(Line::raw(format!("Ability: {}", ability)));
+    lines.push(Line::raw(format!(
+        "Duration: {}",
+        if duration > 1 {
+            turns(duration)
+        } else {
+            "instant".into()
+        }
+    )));
+    for targeted_effect in action.additional_effects() {
+        let target = match targeted_effect.target {
+            EffectTarget::Own => "self",
+            EffectTarget::Opponent => "opponent",
+        };
+        lines.push(Line::raw(format!(
+            "Also: {} on {}",
+            effect_text(&targeted_effect.effect),
+            target
+        )));
+    }
+    lines
+}
+
+fn philosopher_lines(
+    philosopher: &Philosopher,
+    health: String,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let mut lines = title_lines(&philosopher.name, &philosopher.school, theme);
+    lines.push(Line::raw(""));
+    lines.push(Line::raw(format!("Health: {}", health)));
+    lines
+}
+
+/// A card in hand: name, school, and what it does when played.
+pub struct CardWidget<'a> {
+    card: &'a Card,
+    theme: &'a Theme,
+    block: Option<Block<'a>>,
+}
+impl<'a> CardWidget<'a> {
+    pub fn new(card: &'a Card, theme: &'a Theme) -> Self {
+        CardWidget {
+            card,
+            theme,
+            block: None,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        match self.card {
+            Card::Action(action) => action_lines(action, self.theme),
+            Card::Philosopher(philosopher) => philosopher_lines(
+                philosopher,
+                philosopher.starting_health.to_string(),
+                self.theme,
+            ),
+            Card::InPlayPhilosopher(in_play_philosopher) => {
+                let mut lines = philosopher_lines(
+                    &in_play_philosopher.philosopher,
+                    format!(
+                        "{}/{}",
+                        in_play_philosopher.remaining_health(),
+                        in_play_philosopher.philosopher.starting_health
+                    ),
+                    self.theme,
+                );
+                lines.extend(
+                    in_play_philosopher
+                        .effects
+                        .iter()
+                        .map(|active_effect| active_effect_line(active_effect, self.theme)),
+                );
+                lines
+            }
+        }
+    }
+}
+impl Widget for CardWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut paragraph = Paragraph::new(self.lines())
+            .centered()
+            .wrap(Wrap { trim: true });
+        if let Some(block) = self.block {
+            let accent = self.theme.school(self.card.school());
+            paragraph = paragraph.block(block.border_style(Style::default().fg(accent)));
+        }
+        paragraph.render(area, buf);
+    }
+}
+
+/// The philosopher in play for one seat, with a health bar and its active effects.
+pub struct PhilosopherWidget<'a> {
+    philosopher: Option<&'a InPlayPhilosopher>,
+    theme: &'a Theme,
+    block: Option<Block<'a>>,
+}
+impl<'a> PhilosopherWidget<'a> {
+    pub fn new(philosopher: Option<&'a InPlayPhilosopher>, theme: &'a Theme) -> Self {
+        PhilosopherWidget {
+            philosopher,
+            theme,
+            block: None,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+impl Widget for PhilosopherWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let accent = self
+            .philosopher
+            .map(|philosopher| self.theme.school(&philosopher.philosopher.school));
+        let inner_area = match self.block {
+            Some(block) => {
+                let block = match accent {
+                    Some(accent) => block.border_style(Style::default().fg(accent)),
+                    None => block,
+                };
+                let inner_area = block.inner(area);
+                block.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        let Some(philosopher) = self.philosopher else {
+            Paragraph::new("No philosopher in play")
+                .centered()
+                .render(inner_area, buf);
+            return;
+        };
+
+        let [title_area, health_area, effects_area] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(inner_area);
+        Paragraph::new(title_lines(
+            &philosopher.philosopher.name,
+            &philosopher.philosopher.school,
+            self.theme,
+        ))
+        .centered()
+        .render(title_area, buf);
+
+        let starting_health = philosopher.philosopher.starting_health;
+        let remaining_health = philosopher.remaining_health();
+        let health_ratio = f64::from(remaining_health) / f64::from(starting_health.max(1));
+        Gauge::default()
+            .gauge_style(Style::default().fg(if health_ratio > 0.3 {
+                self.theme.health_high
+            } else {
+                self.theme.health_low
+            }))
+            .ratio(health_ratio.clamp(0.0, 1.0))
+            .label(format!("{}/{} health", remaining_health, starting_health))
+            .render(health_area, buf);
+
+        Paragraph::new(
+            philosopher
+                .effects
+                .iter()
+                .map(|active_effect| active_effect_line(active_effect, self.theme))
+                .collect::<Vec<_>>(),
+        )
+        .centered()
+        .render(effects_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_to_string(widget: impl Widget, width: u16, height: u16) -> String {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        buf.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn test_card_widget_shows_action_details() {
+        let card = Card::Action(Action::new(
+            "Radical Doubt".into(),
+            "Doubt everything".into(),
+            CoreSchool::Skeptic,
+            AbilityType::Damage {
+                damage: 2,
+                duration: 3,
+            },
+            None,
+        ));
+        let rendered = render_to_string(CardWidget::new(&card, &Theme::default()), 40, 10);
+        for expected in [
+            "Radical Doubt",
+            "Skeptic",
+            "Doubt everything",
+            "Damage 2",
+            "3 turns",
+        ] {
+            assert!(rendered.contains(expected), "missing {:?}", expected);
+        }
+    }
+
+    #[test]
+    fn test_philosopher_widget_shows_health_and_effects() {
+        let mut philosopher = InPlayPhilosopher::new(Philosopher::new(
+            "Plato".into(),
+            CoreSchool::Rationalist,
+            12,
+        ));
+        philosopher.apply_direct_damage(4);
+        philosopher.add_effect(
+            Effect::Poison {
+                damage: 2,
+                duration: 2,
+            },
+            "Radical Doubt",
+        );
+        let rendered = render_to_string(
+            PhilosopherWidget::new(Some(&philosopher), &Theme::default()),
+            60,
+            5,
+        );
+        assert!(rendered.contains("Plato"));
+        assert!(rendered.contains("8/12 health"));
+        assert!(rendered.contains("Poison 2 (2 turns left, from Radical Doubt)"));
+    }
+
+    #[test]
+    fn test_philosopher_widget_without_philosopher() {
+        let rendered = render_to_string(PhilosopherWidget::new(None, &Theme::default()), 30, 1);
+        assert!(rendered.contains("No philosopher in play"));
+    }
+
+    #[test]
+    fn test_card_widget_border_uses_school_color() {
+        let theme = Theme::default();
+        let card = Card::Philosopher(Philosopher::new("Hume".into(), CoreSchool::Empiricist, 10));
+        let area = Rect::new(0, 0, 20, 6);
+        let mut buf = Buffer::empty(area);
+        CardWidget::new(&card, &theme)
+            .block(Block::bordered())
+            .render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].fg, theme.schools.empiricist);
+    }
+}
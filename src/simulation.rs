@@ -0,0 +1,351 @@
+use crate::controllers::{ControllerKind, Player};
+use crate::entities::Card;
+use crate::game_error::GameError;
+use crate::game_management::{get_action_cards, GameBoard, GameConfig, GameOverReason, Seat};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::collections::BTreeMap;
+
+// games still running after this many turns are counted as unfinished
+const MAX_TURNS_PER_GAME: u32 = 200;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationConfig {
+    num_games: u32,
+    player_1: ControllerKind,
+    player_2: ControllerKind,
+    seed: u64,
+}
+impl SimulationConfig {
+    pub fn new(
+        num_games: u32,
+        player_1: ControllerKind,
+        player_2: ControllerKind,
+        seed: u64,
+    ) -> Result<Self, String> {
+        if player_1 == ControllerKind::Human || player_2 == ControllerKind::Human {
+            return Err("simulations can only be run between AI controllers".into());
+        }
+        Ok(SimulationConfig {
+            num_games,
+            player_1,
+            player_2,
+            seed,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PhilosopherStats {
+    pub games: u32,
+    pub wins: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActionStats {
+    pub played: u32,
+    pub played_by_winner: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationReport {
+    config: SimulationConfig,
+    pub player_1_wins: u32,
+    pub player_2_wins: u32,
+    pub unfinished_games: u32,
+    pub total_rounds: u32,
+    // games where both seats were dealt the same philosopher, left out of the tables below
+    pub mirror_matches: u32,
+    pub starting_philosophers: BTreeMap<String, PhilosopherStats>,
+    // keyed by (philosopher, opponent), with wins counted for the first
+    pub head_to_head: BTreeMap<(String, String), PhilosopherStats>,
+    pub actions: BTreeMap<String, ActionStats>,
+}
+impl SimulationReport {
+    pub fn finished_games(&self) -> u32 {
+        self.player_1_wins + self.player_2_wins
+    }
+
+    pub fn average_rounds(&self) -> f64 {
+        f64::from(self.total_rounds) / f64::from(self.finished_games().max(1))
+    }
+}
+impl std::fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let percent = |part: u32, whole: u32| 100.0 * f64::from(part) / f64::from(whole.max(1));
+        writeln!(
+            f,
+            "Simulated {} games of {} vs {} (seed {})",
+            self.config.num_games, self.config.player_1, self.config.player_2, self.config.seed
+        )?;
+        writeln!(
+            f,
+            "Player 1 wins: {} ({:.1}%), Player 2 wins: {} ({:.1}%), unfinished: {}",
+            self.player_1_wins,
+            percent(self.player_1_wins, self.finished_games()),
+            self.player_2_wins,
+            percent(self.player_2_wins, self.finished_games()),
+            self.unfinished_games
+        )?;
+        writeln!(
+            f,
+            "Average game length: {:.1} rounds",
+            self.average_rounds()
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<30} {:>8} {:>8} {:>9}",
+            "Starting philosopher", "games", "wins", "win rate"
+        )?;
+        for (name, stats) in &self.starting_philosophers {
+            writeln!(
+                f,
+                "{:<30} {:>8} {:>8} {:>8.1}%",
+                name,
+                stats.games,
+                stats.wins,
+                percent(stats.wins, stats.games)
+            )?;
+        }
+        writeln!(
+            f,
+            "Mirror matches (same starting philosopher): {}",
+            self.mirror_matches
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<30} {:>8} {:>8} {:>9}",
+            "Head to head", "games", "wins", "win rate"
+        )?;
+        for ((name, opponent), stats) in &self.head_to_head {
+            writeln!(
+                f,
+                "{:<30} {:>8} {:>8} {:>8.1}%",
+                format!("{} vs {}", name, opponent),
+                stats.games,
+                stats.wins,
+                percent(stats.wins, stats.games)
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{:<30} {:>8} {:>10}", "Action", "played", "by winner")?;
+        for (name, stats) in &self.actions {
+            writeln!(
+                f,
+                "{:<30} {:>8} {:>10}",
+                name, stats.played, stats.played_by_winner
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct GameRecord {
+    winner: Option<Seat>,
+    num_turns: u32,
+    starting_philosophers: [Option<String>; 2],
+    actions_played: Vec<(Seat, String)>,
+}
+
+fn seat_index(seat: &Seat) -> usize {
+    match seat {
+        Seat::Player1 => 0,
+        Seat::Player2 => 1,
+    }
+}
+
+fn play_game(
+    game_board: &mut GameBoard,
+    player_1: &mut dyn Player,
+    player_2: &mut dyn Player,
+) -> GameRecord {
+    let starting_philosopher =
+        |seat: &Seat| game_board.starting_philosopher(seat).map(String::from);
+    let mut record = GameRecord {
+        winner: None,
+        num_turns: 0,
+        starting_philosophers: [
+            starting_philosopher(&Seat::Player1),
+            starting_philosopher(&Seat::Player2),
+        ],
+        actions_played: vec![],
+    };
+    while record.num_turns < MAX_TURNS_PER_GAME {
+        let Some(seat) = game_board.game_phase().active_seat() else {
+            break;
+        };
+        let turn = match seat {
+            Seat::Player1 => player_1.choose_turn(game_board),
+            Seat::Player2 => player_2.choose_turn(game_board),
+        };
+        let hand = game_board.hand(&seat);
+        let played_actions: Vec<String> = turn
+            .iter()
+            .filter_map(|card_id| match &hand.get_card(card_id)?.card {
+                Card::Action(action) => Some(action.name().to_string()),
+                _ => None,
+            })
+            .collect();
+        // an AI with no legal turn has nothing left to do, so the game can't finish
+        if game_board.process_turn(turn).is_err() {
+            break;
+        }
+        record.num_turns += 1;
+        record
+            .actions_played
+            .extend(played_actions.into_iter().map(|name| (seat, name)));
+    }
+    record.winner = match game_board.game_over_reason() {
+        Some(GameOverReason::PhilosopherDefeated { loser })
+        | Some(GameOverReason::OutOfCards { loser, .. }) => Some(loser.other()),
+        None => None,
+    };
+    record
+}
+
+pub fn run_simulation(config: &SimulationConfig) -> Result<SimulationReport, GameError> {
+    let mut report = SimulationReport {
+        config: config.clone(),
+        player_1_wins: 0,
+        player_2_wins: 0,
+        unfinished_games: 0,
+        total_rounds: 0,
+        mirror_matches: 0,
+        starting_philosophers: BTreeMap::new(),
+        head_to_head: BTreeMap::new(),
+        actions: BTreeMap::new(),
+    };
+    for card in get_action_cards()? {
+        if let Card::Action(action) = card {
+            report
+                .actions
+                .insert(action.name().to_string(), ActionStats::default());
+        }
+    }
+
+    let mut seeds = StdRng::seed_from_u64(config.seed);
+    for _ in 0..config.num_games {
+        let game_config = GameConfig::default().with_seed(seeds.next_u64());
+        let mut game_board = GameBoard::new(Some(game_config))?;
+        let mut player_1 = config
+            .player_1
+            .build(Some(seeds.next_u64()))
+            .expect("simulation controllers are always AIs");
+        let mut player_2 = config
+            .player_2
+            .build(Some(seeds.next_u64()))
+            .expect("simulation controllers are always AIs");
+        let record = play_game(&mut game_board, player_1.as_mut(), player_2.as_mut());
+
+        match record.winner {
+            Some(Seat::Player1) => report.player_1_wins += 1,
+            Some(Seat::Player2) => report.player_2_wins += 1,
+            None => report.unfinished_games += 1,
+        }
+        if record.winner.is_some() {
+            report.total_rounds += record.num_turns.div_ceil(2);
+        }
+        let [player_1_philosopher, player_2_philosopher] = &record.starting_philosophers;
+        if player_1_philosopher == player_2_philosopher {
+            report.mirror_matches += 1;
+        } else {
+            for seat in [Seat::Player1, Seat::Player2] {
+                let (Some(name), Some(opponent)) = (
+                    &record.starting_philosophers[seat_index(&seat)],
+                    &record.starting_philosophers[seat_index(&seat.other())],
+                ) else {
+                    continue;
+                };
+                let won = record.winner == Some(seat);
+                for stats in [
+                    report
+                        .starting_philosophers
+                        .entry(name.clone())
+                        .or_default(),
+                    report
+                        .head_to_head
+                        .entry((name.clone(), opponent.clone()))
+                        .or_default(),
+                ] {
+                    stats.games += 1;
+                    if won {
+                        stats.wins += 1;
+                    }
+                }
+            }
+        }
+        for (seat, name) in record.actions_played {
+            let stats = report.actions.entry(name).or_default();
+            stats.played += 1;
+            if record.winner == Some(seat) {
+                stats.played_by_winner += 1;
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_example_config(seed: u64) -> SimulationConfig {
+        SimulationConfig::new(5, ControllerKind::RandomAi, ControllerKind::GreedyAi, seed).unwrap()
+    }
+
+    #[test]
+    fn test_simulation_config_rejects_humans() {
+        assert!(
+            SimulationConfig::new(1, ControllerKind::Human, ControllerKind::RandomAi, 0).is_err()
+        );
+    }
+
+    #[test]
+    fn test_run_simulation_counts_every_game() {
+        let report = run_simulation(&get_example_config(42)).unwrap();
+        assert_eq!(
+            report.finished_games() + report.unfinished_games,
+            report.config.num_games
+        );
+        let starting_games: u32 = report.starting_philosophers.values().map(|s| s.games).sum();
+        assert_eq!(
+            starting_games,
+            2 * (report.config.num_games - report.mirror_matches)
+        );
+        let head_to_head_games: u32 = report.head_to_head.values().map(|s| s.games).sum();
+        assert_eq!(head_to_head_games, starting_games);
+        for stats in report.actions.values() {
+            assert!(stats.played_by_winner <= stats.played);
+        }
+    }
+
+    #[test]
+    fn test_run_simulation_records_dealt_starting_philosophers() {
+        // greedy players open with the same philosopher, so counting the one played first
+        // made every game a mirror match and put every philosopher at exactly 50%
+        let config =
+            SimulationConfig::new(30, ControllerKind::GreedyAi, ControllerKind::GreedyAi, 0)
+                .unwrap();
+        let report = run_simulation(&config).unwrap();
+        assert!(report.mirror_matches < config.num_games);
+        assert!(report
+            .starting_philosophers
+            .values()
+            .any(|stats| 2 * stats.wins != stats.games));
+    }
+
+    #[test]
+    fn test_run_simulation_lists_every_action() {
+        let report = run_simulation(&get_example_config(42)).unwrap();
+        assert_eq!(report.actions.len(), get_action_cards().unwrap().len());
+    }
+
+    #[test]
+    fn test_run_simulation_with_seed_is_repeatable() {
+        assert_eq!(
+            run_simulation(&get_example_config(7)).unwrap(),
+            run_simulation(&get_example_config(7)).unwrap()
+        );
+    }
+}
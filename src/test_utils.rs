@@ -1,8 +1,10 @@
 #[cfg(test)]
-use crate::entities::{AbilityType, Action, Card, CoreSchool};
+use crate::entities::{AbilityType, Action, Card, CardId, CardInstance, CoreSchool};
 #[cfg(test)]
 use crate::entities::{InPlayPhilosopher, Philosopher};
 #[cfg(test)]
+use crate::game_management::GameBoard;
+#[cfg(test)]
 use crate::player::PlayerHand;
 
 #[cfg(test)]
@@ -12,18 +14,20 @@ pub fn get_example_in_play_philosopher(name: String, starting_health: u8) -> InP
 }
 
 #[cfg(test)]
-pub fn get_populated_player_hand(in_play_philosopher_health: u8) -> PlayerHand {
+pub fn get_populated_player_hand(in_play_philosopher_health: u8, first_card_id: u32) -> PlayerHand {
     let example_philosopher = Philosopher::new(
         "test".into(),
         CoreSchool::Skeptic,
         in_play_philosopher_health,
     );
-    let player_hand = PlayerHand {
+    let inactive_cards = get_example_cards(first_card_id);
+    let active_philosopher_id = CardId(first_card_id + inactive_cards.len() as u32);
+    PlayerHand {
         active_philosopher: Some(InPlayPhilosopher::new(example_philosopher)),
-        inactive_cards: get_example_cards(),
+        active_philosopher_id: Some(active_philosopher_id),
+        inactive_cards,
         max_cards_in_hand: 8,
-    };
-    player_hand
+    }
 }
 
 #[cfg(test)]
@@ -49,7 +53,7 @@ pub fn get_example_heal_action(heal: u8, duration: u8) -> Card {
 }
 
 #[cfg(test)]
-pub fn get_example_cards() -> Vec<Box<Card>> {
+pub fn get_example_cards(first_card_id: u32) -> Vec<CardInstance> {
     let cards = vec![
         get_example_damage_action(1, 0),
         get_example_heal_action(3, 2),
@@ -59,6 +63,25 @@ pub fn get_example_cards() -> Vec<Box<Card>> {
             6,
         )),
     ];
-    let cards: Vec<Box<Card>> = cards.into_iter().map(|card| Box::new(card)).collect();
     cards
+        .into_iter()
+        .zip(first_card_id..)
+        .map(|(card, id)| CardInstance {
+            id: CardId(id),
+            card,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub fn get_example_board() -> GameBoard {
+    let p1_hand = get_populated_player_hand(10, 0);
+    let p2_hand = get_populated_player_hand(12, 100);
+    GameBoard::from_hands(p1_hand, p2_hand)
+}
+
+/// A fresh folder path under the system temp dir; tests create and remove it themselves.
+#[cfg(test)]
+pub fn get_temp_dir(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("infinite_debate_{}_{}", name, uuid::Uuid::new_v4()))
 }